@@ -7,21 +7,28 @@ mod types;
 #[cfg(test)]
 mod test;
 
-use soroban_sdk::{contract, contractimpl, Bytes, Env, String, Address};
+use soroban_sdk::{contract, contractimpl, symbol_short, xdr::ToXdr, Bytes, BytesN, Env, String, Address};
 use errors::Error;
-use types::{DataKey, Dispute, DisputeResult, DisputeStatus};
+use types::{Dispute, DisputeResult, DisputeStatus};
 
 const VOTING_PERIOD: u64 = 604_800; // 7 days in seconds
+const SCHEMA_VERSION: u32 = 1; // bump when Dispute gains or loses a field
 
 fn generate_dispute_id(env: &Env, split_id: &String) -> String {
-    let mut input = Bytes::new(env);
-    input.append(&split_id.to_bytes());
+    let mut input = split_id.clone().to_xdr(env);
     let seq = env.ledger().sequence().to_be_bytes();
     input.append(&Bytes::from_slice(env, &seq));
     let hash = env.crypto().sha256(&input);
     let hash_bytes = &hash.to_array()[..8];
-    let mut id_bytes = String::from_str(env, "dis_").to_bytes();
-    id_bytes.append(&Bytes::from_slice(env, hash_bytes));
+
+    // "dis_" then the hash bytes in hex, so the ID is readable text
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut id_bytes = [0u8; 20];
+    id_bytes[..4].copy_from_slice(b"dis_");
+    for (i, byte) in hash_bytes.iter().enumerate() {
+        id_bytes[4 + 2 * i] = HEX[(byte >> 4) as usize];
+        id_bytes[5 + 2 * i] = HEX[(byte & 0x0f) as usize];
+    }
     String::from_bytes(env, &id_bytes)
 }
 
@@ -31,6 +38,56 @@ pub struct DisputeContract;
 #[contractimpl]
 impl DisputeContract {
 
+    /// Set the admin who may upgrade the contract.
+    ///
+    /// Whoever calls this first becomes the admin, so it must be called in
+    /// the same transaction that deploys the contract, e.g. by the factory
+    /// contract that deploys it. A separate call can be front-run.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if storage::has_admin(&env) {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        storage::set_admin(&env, &admin);
+        storage::set_schema_version(&env, SCHEMA_VERSION);
+
+        Ok(())
+    }
+
+    /// Replace the contract code with an uploaded WASM. Storage is kept.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        storage::get_admin(&env)?.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events()
+            .publish((symbol_short!("upgraded"),), (new_wasm_hash,));
+
+        Ok(())
+    }
+
+    /// Get the layout version of the stored disputes.
+    pub fn get_schema_version(env: Env) -> u32 {
+        storage::get_schema_version(&env)
+    }
+
+    /// Bring stored disputes up to the current schema version.
+    pub fn migrate(env: Env) -> Result<u32, Error> {
+        storage::get_admin(&env)?.require_auth();
+
+        // Dispute hasn't changed shape since the first release, so there is
+        // nothing to rewrite yet; older deployments just record the version
+        let from_version = storage::get_schema_version(&env);
+        if from_version < SCHEMA_VERSION {
+            storage::set_schema_version(&env, SCHEMA_VERSION);
+            env.events()
+                .publish((symbol_short!("migrated"),), (from_version, SCHEMA_VERSION));
+        }
+
+        Ok(SCHEMA_VERSION)
+    }
+
     /// Raise a new dispute against a split.
     pub fn raise_dispute(
         env: Env,
@@ -58,7 +115,7 @@ impl DisputeContract {
             voters: soroban_sdk::Vec::new(&env),
             created_at: now,
             voting_ends_at: now + VOTING_PERIOD,
-            result: None,
+            result: DisputeResult::Pending,
         };
 
         storage::save_dispute(&env, &dispute);
@@ -137,7 +194,7 @@ impl DisputeContract {
         };
 
         dispute.status = DisputeStatus::Resolved;
-        dispute.result = Some(result.clone());
+        dispute.result = result.clone();
 
         storage::save_dispute(&env, &dispute);
 
@@ -50,4 +50,33 @@ pub fn record_vote(env: &Env, dispute_id: &String, voter: &Address) {
     env.storage()
         .persistent()
         .set(&DataKey::VoterRecord(dispute_id.clone(), voter.clone()), &true);
+}
+
+pub fn get_admin(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Admin)
+        .ok_or(Error::NotInitialized)
+}
+
+pub fn has_admin(env: &Env) -> bool {
+    env.storage().persistent().has(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().persistent().set(&DataKey::Admin, admin);
+}
+
+// Deployments from before versioning have no key, which reads as 0
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SchemaVersion, &version);
 }
\ No newline at end of file
@@ -12,6 +12,7 @@ pub enum DisputeStatus {
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub enum DisputeResult {
+    Pending,            // Not resolved yet
     UpheldForRaiser,    // Dispute was valid, raiser wins
     DismissedForRaiser, // Dispute was invalid, original split stands
     Tied,               // Equal votes, default to original split
@@ -30,7 +31,7 @@ pub struct Dispute {
     pub voters: Vec<Address>,
     pub created_at: u64,
     pub voting_ends_at: u64, // voting window: 7 days
-    pub result: Option<DisputeResult>,
+    pub result: DisputeResult,
 }
 
 #[contracttype]
@@ -38,4 +39,6 @@ pub enum DataKey {
     Dispute(String),
     DisputeList,
     VoterRecord(String, Address), // (dispute_id, voter) -> bool (has voted)
+    Admin,                        // may upgrade and migrate the contract
+    SchemaVersion,                // layout version of stored disputes
 }
\ No newline at end of file
@@ -0,0 +1,68 @@
+//! # Proportional Allocation for Split Escrow Contract
+//!
+//! Dividing an amount by weights rarely comes out even in integers. I'm
+//! using the largest-remainder method everywhere the contract does this, so
+//! every client that repeats the calculation gets the same answer: each
+//! entry gets the floor of its exact share, then the units left over go one
+//! each to the entries with the largest remainders, earliest first on ties.
+//!
+//! The results always sum to the total, and no entry is more than one unit
+//! away from its exact share.
+
+use soroban_sdk::{Env, Vec};
+
+use crate::errors::Error;
+
+/// Divide `total` among `weights` in proportion, by largest remainder
+///
+/// Weights must be non-negative with a positive sum, and `total` must not
+/// be negative. Fails with `InvalidAmount` if `total` times a weight, or
+/// the sum of the weights, doesn't fit in an `i128`.
+pub fn largest_remainder(env: &Env, total: i128, weights: &Vec<i128>) -> Result<Vec<i128>, Error> {
+    let mut weight_sum: i128 = 0;
+    for weight in weights.iter() {
+        weight_sum = weight_sum.checked_add(weight).ok_or(Error::InvalidAmount)?;
+    }
+
+    let mut shares = Vec::new(env);
+    let mut remainders = Vec::new(env);
+    let mut allocated: i128 = 0;
+    for weight in weights.iter() {
+        let exact = total.checked_mul(weight).ok_or(Error::InvalidAmount)?;
+        shares.push_back(exact / weight_sum);
+        remainders.push_back(exact % weight_sum);
+        allocated += exact / weight_sum;
+    }
+
+    // Fewer units are left over than there are entries, so each entry gets
+    // at most one of them
+    let mut left_over = total - allocated;
+    while left_over > 0 {
+        let mut best = 0;
+        for i in 1..remainders.len() {
+            if remainders.get(i).unwrap() > remainders.get(best).unwrap() {
+                best = i;
+            }
+        }
+
+        shares.set(best, shares.get(best).unwrap() + 1);
+        remainders.set(best, -1);
+        left_over -= 1;
+    }
+
+    Ok(shares)
+}
+
+/// Divide `total` into `count` equal parts
+///
+/// When it doesn't divide evenly, the first `total % count` parts get one
+/// unit more than the rest, which is what `largest_remainder` gives for
+/// equal weights.
+pub fn equal_shares(env: &Env, total: i128, count: u32) -> Vec<i128> {
+    let mut shares = Vec::new(env);
+    for i in 0..count {
+        let extra = if (i as i128) < total % count as i128 { 1 } else { 0 };
+        shares.push_back(total / count as i128 + extra);
+    }
+    shares
+}
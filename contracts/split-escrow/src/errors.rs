@@ -1,15 +1,151 @@
+//! # Error Codes for Split Escrow Contract
+//!
+//! I'm keeping every error the contract can return in this one enum so the
+//! numeric codes clients see stay unique and stable. Codes are never reused
+//! or renumbered; new errors are appended at the end.
+//!
+//! The contract spec allows at most 50 cases in an error enum, so similar
+//! failures share a variant: the string-keyed escrows report the `Split*`
+//! errors, every config check reports `InvalidConfig`, and a non-positive
+//! premium reports `InvalidAmount`. The gaps in the numbering are codes
+//! retired by those merges.
+//!
+//! An earlier draft of this file had its own enum whose codes clashed with
+//! the ones below. Its `AlreadyInitialized` lives on as code 51, and its
+//! participant and payment errors map onto `ParticipantNotFound` and
+//! `ExceedsOwed`.
+
 use soroban_sdk::contracterror;
 
+/// Contract errors
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
 pub enum Error {
-    AlreadyInitialized = 1,
-    NotInitialized = 2,
-    EscrowNotFound = 3,
-    EscrowExpired = 4,
-    InvalidParticipant = 5,
-    InvalidAmount = 6,
-    AlreadyPaid = 7,
-    Unauthorized = 8,
-    ParticipantNotFound = 9
+    // Splits
+    /// No split or escrow exists with this ID
+    SplitNotFound = 1,
+    /// The split was cancelled
+    SplitCancelled = 2,
+    /// The split's funds were already released
+    SplitReleased = 3,
+    /// The split hasn't collected its full total yet
+    SplitNotFunded = 4,
+    /// The split is fully funded, so a partial release isn't allowed
+    SplitFullyFunded = 5,
+    /// There's nothing left to release or refund
+    NoFundsAvailable = 6,
+    /// An amount was zero, negative or otherwise out of range
+    InvalidAmount = 7,
+
+    // Insurance
+    /// No insurance policy exists with this ID
+    InsuranceNotFound = 8,
+    /// The split is already insured
+    InsuranceAlreadyExists = 9,
+    /// No claim exists with this ID
+    ClaimNotFound = 10,
+    /// The claim has already been processed
+    InvalidClaimStatus = 11,
+    /// The policy has passed its expiry
+    InsuranceExpired = 13,
+
+    // Splits
+    /// The address isn't a participant in this split
+    ParticipantNotFound = 14,
+
+    // Rewards
+    /// No rewards record exists for this user
+    UserNotFound = 15,
+    /// The user has no rewards available to claim
+    InsufficientRewards = 16,
+    /// The rewards were already claimed
+    RewardsAlreadyClaimed = 17,
+
+    // Verification
+    /// No verification request exists with this ID
+    VerificationNotFound = 18,
+    /// The split already has a pending verification request
+    VerificationAlreadyExists = 19,
+    /// The request has already been decided or expired
+    InvalidVerificationStatus = 20,
+    /// The caller isn't a configured oracle
+    OracleNotAuthorized = 21,
+    /// Fewer oracles are configured than `min_oracles`
+    InsufficientOracles = 22,
+
+    // Refunds and deadlines
+    /// The split hasn't been cancelled or expired, so refunds aren't open
+    RefundsNotOpen = 23,
+    /// The split or escrow passed its deadline, or was created with one
+    /// already in the past
+    SplitExpired = 24,
+    /// The split or escrow's deadline hasn't passed yet
+    SplitNotExpired = 25,
+
+    // String-keyed escrows
+    /// An escrow with this ID already exists
+    EscrowAlreadyExists = 27,
+    /// The escrow is no longer accepting payments
+    EscrowNotActive = 28,
+    /// The escrow failed validation, or a split listed the same address
+    /// twice
+    InvalidEscrow = 31,
+    /// The caller isn't allowed to do this
+    Unauthorized = 32,
+
+    // Insurance pool
+    /// The policy isn't active
+    InsuranceNotActive = 33,
+    /// The pool can't pay this claim out of its reserves
+    InsufficientPoolFunds = 34,
+    /// The policy would push the pool below its minimum reserve ratio
+    InsufficientPoolReserves = 35,
+    /// The policy hasn't reached its expiry yet
+    InsuranceNotExpired = 36,
+
+    // Rewards treasury
+    /// The rewards treasury can't cover this claim
+    InsufficientRewardsTreasury = 37,
+    /// A rewards, oracle, fee or other admin config failed validation
+    InvalidConfig = 38,
+
+    // Oracle quorum
+    /// The request ran past its verification timeout
+    VerificationExpired = 39,
+    /// The request is still within its verification timeout
+    VerificationNotExpired = 40,
+    /// This oracle already voted on the request, or this council signer
+    /// already approved the admin action
+    AlreadyAttested = 41,
+    /// The oracle is already registered
+    OracleAlreadyExists = 43,
+    /// The oracle isn't registered
+    OracleNotFound = 44,
+    /// The split must be verified before its funds can be released
+    VerificationRequired = 45,
+
+    // Split creation and deposits
+    /// Participant addresses and shares have different lengths
+    LengthMismatch = 46,
+    /// The split has no participants, or was given an empty recipient list
+    NoParticipants = 47,
+    /// Participant shares don't sum to the split's total
+    SharesMismatch = 48,
+    /// The split is no longer accepting deposits
+    NotAcceptingDeposits = 49,
+    /// The deposit is more than the participant still owes, or a new share
+    /// would be less than they've already paid
+    ExceedsOwed = 50,
+    /// The contract has already been initialized
+    AlreadyInitialized = 51,
+    /// The admin has paused this part of the contract
+    ContractPaused = 52,
+    /// There's no admin proposal to accept, or it has expired
+    NoPendingAdmin = 53,
+    /// The token isn't on the admin's allowlist, or can't be used here
+    TokenNotAllowed = 54,
+    /// The address is already in the split, is its last participant, has
+    /// already paid their share in full, or the split is full
+    InvalidParticipantChange = 55,
 }
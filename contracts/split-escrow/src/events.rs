@@ -3,8 +3,12 @@
 //! I'm defining all contract events here for off-chain tracking and indexing.
 //! These events are crucial for the backend to sync with on-chain state.
 
-use soroban_sdk::{symbol_short, Address, Env, String};
-use soroban_sdk::contractevent;
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::types::{
+    ActivityType, FeeBounds, FeeConfig, OracleConfig, PauseScope, RewardsConfig, VerificationPolicy,
+    VolumeRate,
+};
 
 /// Emit when the contract is initialized
 ///
@@ -15,6 +19,88 @@ pub fn emit_initialized(env: &Env, admin: &Address) {
         .publish((symbol_short!("init"),), (admin.clone(),));
 }
 
+/// Emit when the admin proposes a new admin
+pub fn emit_admin_proposed(
+    env: &Env,
+    admin: &Address,
+    proposed_admin: &Address,
+    expires_at: Option<u64>,
+) {
+    env.events().publish(
+        (Symbol::new(env, "admin_proposed"),),
+        (admin.clone(), proposed_admin.clone(), expires_at),
+    );
+}
+
+/// Emit when the admin withdraws a pending proposal
+pub fn emit_admin_proposal_cancelled(env: &Env, proposed_admin: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "admin_proposal_cancelled"),),
+        (proposed_admin.clone(),),
+    );
+}
+
+/// Emit when a proposed admin accepts and takes over
+pub fn emit_admin_changed(env: &Env, old_admin: &Address, new_admin: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "admin_changed"),),
+        (old_admin.clone(), new_admin.clone()),
+    );
+}
+
+/// Emit when the admin council is set or removed
+pub fn emit_admin_council_updated(env: &Env, signers: &Vec<Address>, threshold: u32) {
+    env.events().publish(
+        (Symbol::new(env, "admin_council"),),
+        (signers.clone(), threshold),
+    );
+}
+
+/// Emit when a council signer approves an admin action
+pub fn emit_admin_action_approved(
+    env: &Env,
+    signer: &Address,
+    action_hash: &BytesN<32>,
+    approvals: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "admin_approval"),),
+        (signer.clone(), action_hash.clone(), approvals),
+    );
+}
+
+/// Emit when the admin pauses part of the contract
+pub fn emit_paused(env: &Env, scope: PauseScope) {
+    env.events()
+        .publish((symbol_short!("paused"),), (scope,));
+}
+
+/// Emit when the admin lifts a pause
+pub fn emit_unpaused(env: &Env, scope: PauseScope) {
+    env.events()
+        .publish((symbol_short!("unpaused"),), (scope,));
+}
+
+/// Emit when the admin adds a token to the allowlist or removes one
+pub fn emit_token_allowlist_updated(env: &Env, token: &Address, allowed: bool) {
+    env.events().publish(
+        (Symbol::new(env, "token_allowlist"),),
+        (token.clone(), allowed),
+    );
+}
+
+/// Emit when the admin swaps in new contract code
+pub fn emit_contract_upgraded(env: &Env, new_wasm_hash: &BytesN<32>) {
+    env.events()
+        .publish((symbol_short!("upgraded"),), (new_wasm_hash.clone(),));
+}
+
+/// Emit when stored records finish moving to a new schema version
+pub fn emit_schema_migrated(env: &Env, from_version: u32, to_version: u32) {
+    env.events()
+        .publish((symbol_short!("migrated"),), (from_version, to_version));
+}
+
 /// Emit when a new split is created
 ///
 /// I'm including the key details so the backend can create
@@ -37,20 +123,51 @@ pub fn emit_deposit_received(env: &Env, split_id: u64, participant: &Address, am
     );
 }
 
-/// Emit when funds are released to the creator
+/// Emit when funds are released to a recipient
 ///
-/// I'm including the total amount released for reconciliation
-/// with the backend's payment records.
+/// I'm including the amount paid out and the platform fee kept back for
+/// reconciliation with the backend's payment records.
 pub fn emit_funds_released(
     env: &Env,
     split_id: u64,
     recipient: &Address,
     amount: i128,
+    fee: i128,
     timestamp: u64,
 ) {
     env.events().publish(
         (symbol_short!("released"),),
-        (split_id, recipient.clone(), amount, timestamp),
+        (split_id, recipient.clone(), amount, fee, timestamp),
+    );
+}
+
+/// Emit when the admin changes the platform fee
+pub fn emit_fee_config_updated(env: &Env, config: &FeeConfig) {
+    env.events()
+        .publish((Symbol::new(env, "fee_config"),), (config.clone(),));
+}
+
+/// Emit when the admin sets a token's fee bounds
+pub fn emit_fee_bounds_updated(env: &Env, token: &Address, bounds: &FeeBounds) {
+    env.events().publish(
+        (Symbol::new(env, "fee_bounds"),),
+        (token.clone(), bounds.clone()),
+    );
+}
+
+/// Emit when an address is exempted from platform fees or loses its exemption
+pub fn emit_fee_exemption_updated(env: &Env, address: &Address, exempt: bool) {
+    env.events().publish(
+        (Symbol::new(env, "fee_exemption"),),
+        (address.clone(), exempt),
+    );
+}
+
+/// Emit when collected fees are sent to the fee treasury
+pub fn emit_fees_withdrawn(env: &Env, token: &Address, treasury: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "fees_withdrawn"),),
+        (token.clone(), treasury.clone(), amount),
     );
 }
 
@@ -69,10 +186,18 @@ pub fn emit_split_cancelled(env: &Env, split_id: u64) {
         .publish((symbol_short!("cancel"),), (split_id,));
 }
 
+/// Emit when a split passes its deadline and is expired
+///
+/// I'm emitting this separately from `cancel` so the backend can tell
+/// a creator's decision apart from a missed deadline.
+pub fn emit_split_expired(env: &Env, split_id: u64) {
+    env.events()
+        .publish((symbol_short!("expired"),), (split_id,));
+}
+
 /// Emit when a refund is processed
 ///
 /// I'm tracking each refund individually for audit purposes.
-#[allow(dead_code)]
 pub fn emit_refund_processed(env: &Env, split_id: u64, participant: &Address, amount: i128) {
     env.events().publish(
         (symbol_short!("refund"),),
@@ -80,6 +205,52 @@ pub fn emit_refund_processed(env: &Env, split_id: u64, participant: &Address, am
     );
 }
 
+/// Emit when the creator adds a participant to a split
+pub fn emit_participant_added(
+    env: &Env,
+    split_id: u64,
+    participant: &Address,
+    share: i128,
+    total_amount: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "participant_added"),),
+        (split_id, participant.clone(), share, total_amount),
+    );
+}
+
+/// Emit when the creator removes a participant from a split
+///
+/// I'm including what they were refunded so the backend doesn't need to
+/// match this up with a separate `refund` event.
+pub fn emit_participant_removed(
+    env: &Env,
+    split_id: u64,
+    participant: &Address,
+    refunded: i128,
+    total_amount: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "participant_removed"),),
+        (split_id, participant.clone(), refunded, total_amount),
+    );
+}
+
+/// Emit when the creator changes what a participant owes
+pub fn emit_share_updated(
+    env: &Env,
+    split_id: u64,
+    participant: &Address,
+    old_share: i128,
+    new_share: i128,
+    total_amount: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "share_updated"),),
+        (split_id, participant.clone(), old_share, new_share, total_amount),
+    );
+}
+
 // ============================================
 // Insurance Events
 // ============================================
@@ -94,7 +265,7 @@ pub fn emit_insurance_purchased(
     coverage_amount: i128,
 ) {
     env.events().publish(
-        (symbol_short!("ins_purchased"),),
+        (Symbol::new(env, "ins_purchased"),),
         (
             insurance_id.clone(),
             split_id.clone(),
@@ -114,7 +285,7 @@ pub fn emit_claim_filed(
     claim_amount: i128,
 ) {
     env.events().publish(
-        (symbol_short!("claim_filed"),),
+        (Symbol::new(env, "claim_filed"),),
         (
             claim_id.clone(),
             insurance_id.clone(),
@@ -133,7 +304,7 @@ pub fn emit_claim_processed(
     payout_amount: i128,
 ) {
     env.events().publish(
-        (symbol_short!("claim_processed"),),
+        (Symbol::new(env, "claim_processed"),),
         (
             claim_id.clone(),
             insurance_id.clone(),
@@ -151,19 +322,35 @@ pub fn emit_payout_made(
     amount: i128,
 ) {
     env.events().publish(
-        (symbol_short!("payout_made"),),
+        (Symbol::new(env, "payout_made"),),
         (claim_id.clone(), recipient.clone(), amount),
     );
 }
 
+/// Emit when capital is added to a token's insurance pool
+pub fn emit_pool_capital_deposited(env: &Env, provider: &Address, token: &Address, amount: i128, reserves: i128) {
+    env.events().publish(
+        (Symbol::new(env, "pool_deposit"),),
+        (provider.clone(), token.clone(), amount, reserves),
+    );
+}
+
+/// Emit when the admin changes a pool's minimum reserve ratio
+pub fn emit_reserve_ratio_updated(env: &Env, token: &Address, min_reserve_ratio_bps: u32) {
+    env.events().publish(
+        (Symbol::new(env, "reserve_ratio"),),
+        (token.clone(), min_reserve_ratio_bps),
+    );
+}
+
 /// Emit when user activity is tracked for rewards
 ///
 /// This event is emitted whenever a user performs an action
 /// that contributes to their rewards calculation.
-pub fn emit_activity_tracked(env: &Env, user: &Address, activity_type: &str, split_id: u64, amount: i128) {
+pub fn emit_activity_tracked(env: &Env, user: &Address, activity_type: ActivityType, split_id: u64, amount: i128) {
     env.events()
         .publish(
-            (symbol_short!("activity_tracked"),),
+            (Symbol::new(env, "activity_tracked"),),
             (user.clone(), activity_type, split_id, amount)
         );
 }
@@ -174,7 +361,7 @@ pub fn emit_activity_tracked(env: &Env, user: &Address, activity_type: &str, spl
 pub fn emit_rewards_calculated(env: &Env, user: &Address, total_rewards: i128, available_rewards: i128) {
     env.events()
         .publish(
-            (symbol_short!("rewards_calculated"),),
+            (Symbol::new(env, "rewards_calculated"),),
             (user.clone(), total_rewards, available_rewards)
         );
 }
@@ -185,18 +372,50 @@ pub fn emit_rewards_calculated(env: &Env, user: &Address, total_rewards: i128, a
 pub fn emit_rewards_claimed(env: &Env, user: &Address, amount_claimed: i128) {
     env.events()
         .publish(
-            (symbol_short!("rewards_claimed"),),
+            (Symbol::new(env, "rewards_claimed"),),
             (user.clone(), amount_claimed)
         );
 }
 
+/// Emit when the admin adds tokens to the rewards treasury
+pub fn emit_rewards_funded(env: &Env, funder: &Address, amount: i128, balance: i128) {
+    env.events().publish(
+        (Symbol::new(env, "rewards_funded"),),
+        (funder.clone(), amount, balance),
+    );
+}
+
+/// Emit when the admin changes the rewards token
+pub fn emit_rewards_token_set(env: &Env, token: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "rewards_token"),),
+        (token.clone(),),
+    );
+}
+
+/// Emit when the admin changes the reward formula
+pub fn emit_rewards_config_updated(env: &Env, config: &RewardsConfig) {
+    env.events().publish(
+        (Symbol::new(env, "rewards_config"),),
+        (config.clone(),),
+    );
+}
+
+/// Emit when the admin sets the rate a token's volume earns rewards at
+pub fn emit_volume_rate_updated(env: &Env, token: &Address, rate: &VolumeRate) {
+    env.events().publish(
+        (Symbol::new(env, "volume_rate"),),
+        (token.clone(), rate.clone()),
+    );
+}
+
 /// Emit when verification is submitted for a split
 ///
 /// This event is emitted when someone submits evidence for split verification.
 pub fn emit_verification_submitted(env: &Env, verification_id: &String, split_id: &String, requester: &Address) {
     env.events()
         .publish(
-            (symbol_short!("verification_submitted"),),
+            (Symbol::new(env, "verification_submitted"),),
             (verification_id.clone(), split_id.clone(), requester.clone())
         );
 }
@@ -207,24 +426,114 @@ pub fn emit_verification_submitted(env: &Env, verification_id: &String, split_id
 pub fn emit_verification_completed(env: &Env, verification_id: &String, verified: bool, verifier: &Address) {
     env.events()
         .publish(
-            (symbol_short!("verification_completed"),),
+            (Symbol::new(env, "verification_completed"),),
             (verification_id.clone(), verified, verifier.clone())
         );
 }
 
+/// Emit when an oracle votes on a verification request
+pub fn emit_oracle_attested(env: &Env, verification_id: &String, oracle: &Address, verified: bool) {
+    env.events().publish(
+        (Symbol::new(env, "oracle_attested"),),
+        (verification_id.clone(), oracle.clone(), verified),
+    );
+}
+
+/// Emit when the admin adds an oracle
+pub fn emit_oracle_added(env: &Env, oracle: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "oracle_added"),),
+        (oracle.clone(),),
+    );
+}
+
+/// Emit when the admin removes an oracle
+pub fn emit_oracle_removed(env: &Env, oracle: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "oracle_removed"),),
+        (oracle.clone(),),
+    );
+}
+
+/// Emit when the admin replaces the oracle config
+pub fn emit_oracle_config_updated(env: &Env, config: &OracleConfig) {
+    env.events().publish(
+        (Symbol::new(env, "oracle_config"),),
+        (config.clone(),),
+    );
+}
+
+/// Emit when the admin changes the verification policy
+pub fn emit_verification_policy_updated(env: &Env, policy: &VerificationPolicy) {
+    env.events().publish(
+        (Symbol::new(env, "verification_policy"),),
+        (policy.clone(),),
+    );
+}
+
+/// Emit when a creator opts a split into verification
+pub fn emit_split_verification_required(env: &Env, split_id: u64) {
+    env.events().publish(
+        (Symbol::new(env, "verification_required"),),
+        (split_id,),
+    );
+}
+
 /// Emit when verification status changes
 ///
 /// This event tracks status changes in verification requests.
 pub fn emit_verification_status_changed(env: &Env, verification_id: &String, old_status: &str, new_status: &str) {
     env.events()
         .publish(
-            (symbol_short!("verification_status_changed"),),
+            (Symbol::new(env, "verification_status_changed"),),
             (verification_id.clone(), old_status, new_status)
         );
 }
 
-#[contractevent]
-pub fn escrow_created(split_id: String, creator: Address, total_amount: i128);
+// ============================================
+// Escrow Lifecycle Events (Issue #59)
+// ============================================
+
+/// Emit when a string-keyed escrow is created
+pub fn emit_escrow_created(env: &Env, split_id: &String, creator: &Address, total_amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "escrow_created"),),
+        (split_id.clone(), creator.clone(), total_amount),
+    );
+}
+
+/// Emit when a participant pays into an escrow
+pub fn emit_payment_received(env: &Env, split_id: &String, participant: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "payment_received"),),
+        (split_id.clone(), participant.clone(), amount),
+    );
+}
+
+/// Emit when a fully funded escrow is released to its creator
+pub fn emit_escrow_released(env: &Env, split_id: &String, creator: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "escrow_released"),),
+        (split_id.clone(), creator.clone(), amount),
+    );
+}
 
-#[contractevent]
-pub fn payment_received(split_id: String, participant: Address, amount: i128);
+/// Emit when an escrow is cancelled by its creator
+pub fn emit_escrow_cancelled(env: &Env, split_id: &String) {
+    env.events()
+        .publish((Symbol::new(env, "escrow_cancelled"),), (split_id.clone(),));
+}
+
+/// Emit when an escrow passes its deadline unfunded
+pub fn emit_escrow_expired(env: &Env, split_id: &String) {
+    env.events()
+        .publish((Symbol::new(env, "escrow_expired"),), (split_id.clone(),));
+}
+
+/// Emit when an escrow payment is refunded
+pub fn emit_escrow_refund(env: &Env, split_id: &String, participant: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "escrow_refund"),),
+        (split_id.clone(), participant.clone(), amount),
+    );
+}
@@ -0,0 +1,138 @@
+//! # Number Formatting for Split Escrow Contract
+//!
+//! Contracts are `no_std`, so `format!` and `ToString` aren't available.
+//! I'm writing integers into fixed-size stack buffers instead and building
+//! Soroban `String`s from the bytes, so nothing here allocates.
+//!
+//! The output matches `core::fmt` exactly: `{}` for decimal and `{:x}` for
+//! lowercase hex (two's complement for negative `i128`s).
+
+use soroban_sdk::{Env, String};
+
+/// Longest decimal rendering of a `u64` (`u64::MAX`)
+pub const MAX_U64_DECIMAL_LEN: usize = 20;
+
+/// Longest decimal rendering of an `i128` (`i128::MIN`, including the sign)
+pub const MAX_I128_DECIMAL_LEN: usize = 40;
+
+/// Longest hex rendering of an `i128` (32 nibbles)
+pub const MAX_I128_HEX_LEN: usize = 32;
+
+/// Longest ID produced by `prefixed_id`, prefix included
+pub const MAX_ID_LEN: usize = 32;
+
+const DIGITS: &[u8] = b"0123456789abcdef";
+
+/// Write `value` in the given radix into `buf`, returning the bytes used
+fn write_radix(buf: &mut [u8], mut value: u128, radix: u128) -> usize {
+    // u128::MAX needs 39 decimal digits; hex needs fewer
+    let mut digits = [0u8; 39];
+    let mut n = 0;
+    loop {
+        digits[n] = DIGITS[(value % radix) as usize];
+        n += 1;
+        value /= radix;
+        if value == 0 {
+            break;
+        }
+    }
+    for (slot, digit) in buf.iter_mut().zip(digits[..n].iter().rev()) {
+        *slot = *digit;
+    }
+    n
+}
+
+/// Write a `u64` in decimal, returning the number of bytes used
+pub fn write_u64(buf: &mut [u8], value: u64) -> usize {
+    write_radix(buf, value as u128, 10)
+}
+
+/// Write an `i128` in decimal, returning the number of bytes used
+pub fn write_i128(buf: &mut [u8], value: i128) -> usize {
+    if value < 0 {
+        buf[0] = b'-';
+        1 + write_radix(&mut buf[1..], value.unsigned_abs(), 10)
+    } else {
+        write_radix(buf, value as u128, 10)
+    }
+}
+
+/// Write a `u64` in lowercase hex, returning the number of bytes used
+pub fn write_u64_hex(buf: &mut [u8], value: u64) -> usize {
+    write_radix(buf, value as u128, 16)
+}
+
+/// Write an `i128` in lowercase hex, returning the number of bytes used
+pub fn write_i128_hex(buf: &mut [u8], value: i128) -> usize {
+    write_radix(buf, value as u128, 16)
+}
+
+/// Write raw bytes as lowercase hex, two characters per byte
+pub fn write_bytes_hex(buf: &mut [u8], bytes: &[u8]) -> usize {
+    for (i, byte) in bytes.iter().enumerate() {
+        buf[i * 2] = DIGITS[(byte >> 4) as usize];
+        buf[i * 2 + 1] = DIGITS[(byte & 0x0F) as usize];
+    }
+    bytes.len() * 2
+}
+
+/// Format a `u64` as a decimal `String`
+pub fn u64_to_string(env: &Env, value: u64) -> String {
+    let mut buf = [0u8; MAX_U64_DECIMAL_LEN];
+    let len = write_u64(&mut buf, value);
+    String::from_bytes(env, &buf[..len])
+}
+
+/// Format an `i128` as a decimal `String`
+pub fn i128_to_string(env: &Env, value: i128) -> String {
+    let mut buf = [0u8; MAX_I128_DECIMAL_LEN];
+    let len = write_i128(&mut buf, value);
+    String::from_bytes(env, &buf[..len])
+}
+
+/// Format a `u64` as a lowercase hex `String`
+pub fn u64_to_hex_string(env: &Env, value: u64) -> String {
+    let mut buf = [0u8; 16];
+    let len = write_u64_hex(&mut buf, value);
+    String::from_bytes(env, &buf[..len])
+}
+
+/// Format an `i128` as a lowercase hex `String`
+pub fn i128_to_hex_string(env: &Env, value: i128) -> String {
+    let mut buf = [0u8; MAX_I128_HEX_LEN];
+    let len = write_i128_hex(&mut buf, value);
+    String::from_bytes(env, &buf[..len])
+}
+
+/// Build an ID such as `ins-12` from a short prefix and a counter
+///
+/// The prefix must leave room for the counter within `MAX_ID_LEN`.
+pub fn prefixed_id(env: &Env, prefix: &[u8], value: u64) -> String {
+    let mut buf = [0u8; MAX_ID_LEN];
+    buf[..prefix.len()].copy_from_slice(prefix);
+    let len = prefix.len() + write_u64(&mut buf[prefix.len()..], value);
+    String::from_bytes(env, &buf[..len])
+}
+
+/// Parse a decimal `String` back into a `u64`
+///
+/// Returns `None` for empty input, non-digit characters or overflow, so
+/// `"12abc"` is rejected rather than read as `12`.
+pub fn parse_u64(value: &String) -> Option<u64> {
+    let len = value.len() as usize;
+    if len == 0 || len > MAX_U64_DECIMAL_LEN {
+        return None;
+    }
+
+    let mut buf = [0u8; MAX_U64_DECIMAL_LEN];
+    value.copy_into_slice(&mut buf[..len]);
+
+    let mut result: u64 = 0;
+    for byte in buf[..len].iter() {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        result = result.checked_mul(10)?.checked_add((byte - b'0') as u64)?;
+    }
+    Some(result)
+}
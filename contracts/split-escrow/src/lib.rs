@@ -10,22 +10,54 @@
 //! - Cancel and refund if needed
 
 #![no_std]
+// Entrypoint arguments are the contract's public interface, and the client
+// generated from them inherits the same signatures
+#![allow(clippy::too_many_arguments)]
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Vec, token};
-use soroban_sdk::token::TokenClient;
-use std::string::ToString;
+use soroban_sdk::{
+    contract, contractimpl, token, xdr::ToXdr, Address, BytesN, Env, IntoVal, String, Symbol,
+    Val, Vec,
+};
 
+pub mod allocation;
+mod errors;
 mod events;
+pub mod format;
 mod storage;
 mod types;
 
 #[cfg(test)]
 mod test;
 
+pub use errors::*;
 pub use events::*;
 pub use storage::*;
 pub use types::*;
 
+/// Largest split that `cancel_split` and `expire_split` refund in the same call
+///
+/// Above this, each token transfer risks pushing the cancel transaction over
+/// its resource budget, so participants pull their refund with `claim_refund`.
+const MAX_CANCEL_REFUNDS: u32 = 20;
+
+/// Most recipients a split can pay out to
+///
+/// Every release makes one transfer per recipient, so this keeps releases,
+/// including the automatic one in `deposit`, within budget.
+const MAX_RECIPIENTS: u32 = 20;
+
+/// Coverage bought per unit of premium
+const INSURANCE_COVERAGE_MULTIPLIER: i128 = 10;
+
+/// How long an insurance policy stays active (30 days)
+const INSURANCE_DURATION: u64 = 30 * 24 * 60 * 60;
+
+/// Layout version of the records this build writes
+///
+/// Bump this whenever a stored type gains or loses a field, and teach
+/// `storage::migrate_split` to rewrite the old layout.
+const SCHEMA_VERSION: u32 = 2;
+
 /// The main Split Escrow contract
 ///
 /// I'm keeping the initial implementation minimal - just the structure and
@@ -40,10 +72,10 @@ impl SplitEscrowContract {
     ///
     /// I'm making this the first function to call after deployment.
     /// It sets up the contract administrator who can manage global settings.
-    pub fn initialize(env: Env, admin: Address, token: Address) {
+    pub fn initialize(env: Env, admin: Address, token: Address) -> Result<(), Error> {
         // Ensure the contract hasn't been initialized already
         if storage::has_admin(&env) {
-            panic!("Contract already initialized");
+            return Err(Error::AlreadyInitialized);
         }
 
         // Verify the admin is authorizing this call
@@ -55,14 +87,23 @@ impl SplitEscrowContract {
         // Store the token address
         storage::set_token(&env, &token);
 
+        // Fresh deployments start on the current layout
+        storage::set_schema_version(&env, SCHEMA_VERSION);
+
         // Emit initialization event
         events::emit_initialized(&env, &admin);
+
+        Ok(())
     }
 
     /// Create a new split with the specified participants and amounts
     ///
     /// I'm designing this to be called by the split creator who will also
     /// be responsible for distributing funds once everyone has paid.
+    /// An optional deadline lets anyone expire the split once it passes.
+    /// The split is paid in `token`, which must be on the admin's allowlist;
+    /// without one it uses the contract's default token. Releases go to the
+    /// creator unless `recipients` names who to pay and in what proportion.
     pub fn create_split(
         env: Env,
         creator: Address,
@@ -70,26 +111,54 @@ impl SplitEscrowContract {
         total_amount: i128,
         participant_addresses: Vec<Address>,
         participant_shares: Vec<i128>,
-    ) -> u64 {
+        deadline: Option<u64>,
+        token: Option<Address>,
+        recipients: Option<Vec<Recipient>>,
+    ) -> Result<u64, Error> {
         // Verify the creator is authorizing this call
         creator.require_auth();
 
+        Self::require_not_paused(&env, PauseScope::Deposits)?;
+
         // Validate inputs
         if participant_addresses.len() != participant_shares.len() {
-            panic!("Participant addresses and shares must have the same length");
+            return Err(Error::LengthMismatch);
         }
 
         if participant_addresses.is_empty() {
-            panic!("At least one participant is required");
+            return Err(Error::NoParticipants);
         }
 
-        // Validate shares sum to total
+        if total_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Validate shares are positive and sum to total
         let mut shares_sum: i128 = 0;
         for i in 0..participant_shares.len() {
-            shares_sum += participant_shares.get(i).unwrap();
+            let share = participant_shares.get(i).unwrap();
+            if share <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            shares_sum += share;
         }
         if shares_sum != total_amount {
-            panic!("Participant shares must sum to total amount");
+            return Err(Error::SharesMismatch);
+        }
+
+        if let Some(deadline) = deadline {
+            if deadline <= env.ledger().timestamp() {
+                return Err(Error::SplitExpired);
+            }
+        }
+
+        let token = token.unwrap_or_else(|| storage::get_token(&env));
+        if !storage::is_token_allowed(&env, &token) {
+            return Err(Error::TokenNotAllowed);
+        }
+
+        if let Some(recipients) = &recipients {
+            Self::validate_recipients(recipients)?;
         }
 
         // Get the next split ID
@@ -103,6 +172,7 @@ impl SplitEscrowContract {
                 share_amount: participant_shares.get(i).unwrap(),
                 amount_paid: 0,
                 has_paid: false,
+                amount_refunded: 0,
             };
             participants.push_back(participant);
         }
@@ -118,34 +188,138 @@ impl SplitEscrowContract {
             participants,
             status: SplitStatus::Pending,
             created_at: env.ledger().timestamp(),
+            deadline,
+            token,
         };
 
         // Store the split
         storage::set_split(&env, split_id, &split);
+        if let Some(recipients) = &recipients {
+            storage::set_split_recipients(&env, split_id, recipients);
+        }
 
         // Emit creation event
         events::emit_split_created(&env, split_id, &creator, total_amount);
 
-        split_id
+        Ok(split_id)
+    }
+
+    /// Create a split that divides the total equally between participants
+    ///
+    /// I'm working the shares out here so every client gets the same ones.
+    /// When the total doesn't divide evenly, the first participants in the
+    /// list each pay one unit more, so 100 between three is 34, 33 and 33.
+    /// The total must be at least the number of participants, so everyone
+    /// owes something. The split uses the default token, has no deadline
+    /// and pays the creator.
+    pub fn create_equal_split(
+        env: Env,
+        creator: Address,
+        description: String,
+        total_amount: i128,
+        participants: Vec<Address>,
+    ) -> Result<u64, Error> {
+        if participants.is_empty() {
+            return Err(Error::NoParticipants);
+        }
+
+        if total_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let shares = allocation::equal_shares(&env, total_amount, participants.len());
+        Self::create_split(
+            env,
+            creator,
+            description,
+            total_amount,
+            participants,
+            shares,
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Create a split whose shares are given as basis points of the total
+    ///
+    /// The weights must sum to 10_000, so a template's percentages can be
+    /// used as they are once multiplied by 100. I'm turning them into share
+    /// amounts with `allocation::largest_remainder`, so the shares always
+    /// sum to the total. The split uses the default token, has no deadline
+    /// and pays the creator.
+    pub fn create_weighted_split(
+        env: Env,
+        creator: Address,
+        description: String,
+        total_amount: i128,
+        participant_addresses: Vec<Address>,
+        participant_weights: Vec<u32>,
+    ) -> Result<u64, Error> {
+        if participant_addresses.len() != participant_weights.len() {
+            return Err(Error::LengthMismatch);
+        }
+
+        if participant_addresses.is_empty() {
+            return Err(Error::NoParticipants);
+        }
+
+        if total_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let mut weights = Vec::new(&env);
+        let mut weights_sum: i128 = 0;
+        for weight in participant_weights.iter() {
+            weights.push_back(weight as i128);
+            weights_sum += weight as i128;
+        }
+        if weights_sum != 10_000 {
+            return Err(Error::SharesMismatch);
+        }
+
+        let shares = allocation::largest_remainder(&env, total_amount, &weights)?;
+        Self::create_split(
+            env,
+            creator,
+            description,
+            total_amount,
+            participant_addresses,
+            shares,
+            None,
+            None,
+            None,
+        )
     }
 
     /// Deposit funds into a split
     ///
     /// I'm allowing partial deposits so participants can pay incrementally.
-    pub fn deposit(env: Env, split_id: u64, participant: Address, amount: i128) {
+    /// Deposits arriving after the split's deadline are rejected.
+    pub fn deposit(env: Env, split_id: u64, participant: Address, amount: i128) -> Result<(), Error> {
         // Verify the participant is authorizing this call
         participant.require_auth();
 
+        Self::require_not_paused(&env, PauseScope::Deposits)?;
+
         // Get the split
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
         let mut split = storage::get_split(&env, split_id);
 
         if amount <= 0 {
-            panic!("Deposit amount must be positive");
+            return Err(Error::InvalidAmount);
+        }
+
+        // Late payments are refused even before anyone calls expire_split
+        if split.status == SplitStatus::Expired || Self::is_past_deadline(&env, &split) {
+            return Err(Error::SplitExpired);
         }
 
         // Verify the split is still accepting deposits
         if split.status != SplitStatus::Pending && split.status != SplitStatus::Active {
-            panic!("Split is not accepting deposits");
+            return Err(Error::NotAcceptingDeposits);
         }
 
         // Find the participant in the split
@@ -158,7 +332,7 @@ impl SplitEscrowContract {
                 found = true;
                 let remaining = p.share_amount - p.amount_paid;
                 if amount > remaining {
-                    panic!("Deposit exceeds remaining amount owed");
+                    return Err(Error::ExceedsOwed);
                 }
 
                 p.amount_paid += amount;
@@ -168,12 +342,11 @@ impl SplitEscrowContract {
         }
 
         if !found {
-            panic!("Participant not found in split");
+            return Err(Error::ParticipantNotFound);
         }
 
         // Transfer tokens from participant to escrow contract
-        let token_address = storage::get_token(&env);
-        let token_client = token::Client::new(&env, &token_address);
+        let token_client = token::Client::new(&env, &split.token);
         let contract_address = env.current_contract_address();
         token_client.transfer(&participant, &contract_address, &amount);
 
@@ -196,9 +369,11 @@ impl SplitEscrowContract {
         if Self::is_fully_funded_internal(&split) {
             let _ = Self::release_funds_internal(&env, split_id, split);
         }
+
+        Ok(())
     }
 
-    /// Release funds from a completed split to the creator
+    /// Release funds from a completed split to its recipients
     ///
     /// I'm restricting this to completed splits only for safety.
     pub fn release_funds(env: Env, split_id: u64) -> Result<(), Error> {
@@ -210,8 +385,12 @@ impl SplitEscrowContract {
         Self::release_funds_internal(&env, split_id, split).map(|_| ())
     }
 
-    /// Release available funds to the creator for partial payments
+    /// Release available funds to the recipients for partial payments
+    ///
+    /// Returns the amount released, including any platform fee kept back.
     pub fn release_partial(env: Env, split_id: u64) -> Result<i128, Error> {
+        Self::require_not_paused(&env, PauseScope::Releases)?;
+
         if !storage::has_split(&env, split_id) {
             return Err(Error::SplitNotFound);
         }
@@ -226,30 +405,29 @@ impl SplitEscrowContract {
             return Err(Error::SplitReleased);
         }
 
+        if split.status == SplitStatus::Expired {
+            return Err(Error::SplitExpired);
+        }
+
         if Self::is_fully_funded_internal(&split) {
             return Err(Error::SplitFullyFunded);
         }
 
+        if !Self::is_verification_satisfied(&env, &split) {
+            return Err(Error::VerificationRequired);
+        }
+
         let available = split.amount_collected - split.amount_released;
         if available <= 0 {
             return Err(Error::NoFundsAvailable);
         }
 
-        let token_address = storage::get_token(&env);
-        let token_client = token::Client::new(&env, &token_address);
-        let contract_address = env.current_contract_address();
-        token_client.transfer(&contract_address, &split.creator, &available);
+        Self::pay_release(&env, &split, available)?;
 
         split.amount_released += available;
         storage::set_split(&env, split_id, &split);
 
-        events::emit_funds_released(
-            &env,
-            split_id,
-            &split.creator,
-            available,
-            env.ledger().timestamp(),
-        );
+        Self::record_activity(&env, &split.creator, ActivityType::SplitCompleted, split_id, &split.token, available);
 
         Ok(available)
     }
@@ -264,10 +442,15 @@ impl SplitEscrowContract {
         Ok(Self::is_fully_funded_internal(&split))
     }
 
-    /// Cancel a split and mark for refunds
+    /// Cancel a split and refund deposits
     ///
     /// I'm allowing only the creator to cancel, and only if not fully completed.
-    pub fn cancel_split(env: Env, split_id: u64) {
+    /// Small splits are refunded right here; larger ones leave each participant
+    /// to call `claim_refund` so the cancel itself stays within budget.
+    pub fn cancel_split(env: Env, split_id: u64) -> Result<(), Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
         let mut split = storage::get_split(&env, split_id);
 
         // Only the creator can cancel
@@ -275,364 +458,2308 @@ impl SplitEscrowContract {
 
         // Can't cancel a completed split that's been released
         if split.status == SplitStatus::Released {
-            panic!("Cannot cancel a released split");
+            return Err(Error::SplitReleased);
+        }
+
+        if split.status == SplitStatus::Cancelled {
+            return Err(Error::SplitCancelled);
+        }
+
+        if split.status == SplitStatus::Expired {
+            return Err(Error::SplitExpired);
         }
 
         // Mark as cancelled
         split.status = SplitStatus::Cancelled;
-        storage::set_split(&env, split_id, &split);
 
         // Emit cancellation event
         events::emit_split_cancelled(&env, split_id);
-    }
 
-    /// Get split details by ID
-    pub fn get_split(env: Env, split_id: u64) -> Split {
-        storage::get_split(&env, split_id)
-    }
+        Self::open_refunds(&env, &mut split);
 
-    /// Get the contract admin
-    pub fn get_admin(env: Env) -> Address {
-        storage::get_admin(&env)
-    }
+        storage::set_split(&env, split_id, &split);
 
-    /// Get the token contract address
-    pub fn get_token(env: Env) -> Address {
-        storage::get_token(&env)
+        Ok(())
     }
 
-    // ============================================
-    // Insurance Query Functions
-    // ============================================
+    /// Expire a split whose deadline has passed
+    ///
+    /// I'm leaving this permissionless so a keeper, a participant or the
+    /// backend can close out overdue splits without the creator. Deposits
+    /// are refunded exactly as they would be on cancellation.
+    pub fn expire_split(env: Env, split_id: u64) -> Result<(), Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
 
-    /// Get insurance policy by ID
-    pub fn get_insurance(env: Env, insurance_id: String) -> types::InsurancePolicy {
-        storage::get_insurance(&env, &insurance_id)
-    }
+        let mut split = storage::get_split(&env, split_id);
 
-    /// Get insurance claim by ID
-    pub fn get_claim(env: Env, claim_id: String) -> types::InsuranceClaim {
-        storage::get_claim(&env, &claim_id)
-    }
+        match split.status {
+            SplitStatus::Cancelled => return Err(Error::SplitCancelled),
+            SplitStatus::Released => return Err(Error::SplitReleased),
+            SplitStatus::Expired => return Err(Error::SplitExpired),
+            _ => {}
+        }
 
-    /// Get all claims for an insurance policy
-    pub fn get_insurance_claims(env: Env, insurance_id: String) -> Vec<String> {
-        storage::get_insurance_claims(&env, &insurance_id)
-    }
+        if !Self::is_past_deadline(&env, &split) {
+            return Err(Error::SplitNotExpired);
+        }
 
-    /// Check if a split has insurance
-    pub fn has_split_insurance(env: Env, split_id: String) -> bool {
-        storage::has_split_insurance(&env, &split_id)
-    }
+        split.status = SplitStatus::Expired;
 
-    /// Get insurance ID for a split
-    pub fn get_split_insurance(env: Env, split_id: u64) -> Option<String> {
-        storage::get_split_to_insurance(&env, &split_id.to_string())
+        events::emit_split_expired(&env, split_id);
+
+        Self::open_refunds(&env, &mut split);
+
+        storage::set_split(&env, split_id, &split);
+
+        Ok(())
     }
 
-    /// Track user split usage for rewards calculation
+    /// Claim a refund from a cancelled or expired split
     ///
-    /// This function records user activities that contribute to rewards.
-    pub fn track_split_usage(
-        env: Env,
-        user: Address,
-    ) -> Result<(), Error> {
-        // Get caller's address (require_auth for the caller)
-        let caller = env.current_contract_address();
-        caller.require_auth();
+    /// I'm providing this pull-style path for splits too large to refund
+    /// inside `cancel_split` or `expire_split`. It is also safe to call after
+    /// a push refund; there will simply be nothing left to claim.
+    pub fn claim_refund(env: Env, split_id: u64, participant: Address) -> Result<i128, Error> {
+        participant.require_auth();
 
-        // Get or create user rewards data
-        let mut rewards = if let Some(existing_rewards) = storage::get_user_rewards(&env, &user) {
-            existing_rewards
-        } else {
-            types::UserRewards {
-                user: user.clone(),
-                total_splits_created: 0,
-                total_splits_participated: 0,
-                total_amount_transacted: 0,
-                rewards_earned: 0,
-                rewards_claimed: 0,
-                last_activity: env.ledger().timestamp(),
-                status: types::RewardsStatus::Active,
-            }
-        };
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
 
-        // Create activity record
-        let activity_id = storage::get_next_activity_id(&env);
-        let activity = types::UserActivity {
-            user: user.clone(),
-            activity_type: types::ActivityType::SplitParticipated,
-            split_id: 0, // This would be set based on context
-            amount: 0, // This would be set based on context
-            timestamp: env.ledger().timestamp(),
-        };
+        let mut split = storage::get_split(&env, split_id);
 
-        // Store activity
-        storage::set_user_activity(&env, &user, activity_id, &activity);
+        if split.status != SplitStatus::Cancelled && split.status != SplitStatus::Expired {
+            return Err(Error::RefundsNotOpen);
+        }
 
-        // Update rewards data
-        rewards.total_splits_participated += 1;
-        rewards.last_activity = env.ledger().timestamp();
-        
-        // Store updated rewards
-        storage::set_user_rewards(&env, &user, &rewards);
+        let mut index = None;
+        for i in 0..split.participants.len() {
+            if split.participants.get(i).unwrap().address == participant {
+                index = Some(i);
+                break;
+            }
+        }
+        let index = index.ok_or(Error::ParticipantNotFound)?;
 
-        // Emit activity tracked event
-        events::emit_activity_tracked(&env, &user, "split_participated", 0, 0);
+        let allocations = Self::refund_allocations(&env, &split);
+        let refunded =
+            Self::refund_participant_internal(&env, &mut split, index, allocations.get(index).unwrap());
 
-        Ok(())
+        if refunded == 0 {
+            return Err(Error::NoFundsAvailable);
+        }
+
+        storage::set_split(&env, split_id, &split);
+
+        Ok(refunded)
     }
 
-    /// Calculate rewards for a user
+    /// Add a participant to a split that's still collecting
     ///
-    /// This function calculates the total rewards earned by a user based on their activity.
-    pub fn calculate_rewards(
-        env: Env,
-        user: Address,
-    ) -> i128 {
-        // Get user rewards data
-        let rewards = storage::get_user_rewards(&env, &user)
-            .unwrap_or(types::UserRewards {
-                user: user.clone(),
-                total_splits_created: 0,
-                total_splits_participated: 0,
-                total_amount_transacted: 0,
-                rewards_earned: 0,
-                rewards_claimed: 0,
-                last_activity: env.ledger().timestamp(),
-                status: types::RewardsStatus::Active,
-            });
+    /// The split's total grows by the new share, so the other participants'
+    /// shares stay as they were.
+    pub fn add_participant(env: Env, split_id: u64, participant: Address, share: i128) -> Result<(), Error> {
+        Self::require_not_paused(&env, PauseScope::Deposits)?;
 
-        // Calculate rewards based on activity
-        // Base rewards: 10 tokens per split created
-        let creation_rewards = rewards.total_splits_created as i128 * 10;
-        
-        // Participation rewards: 5 tokens per split participated
-        let participation_rewards = rewards.total_splits_participated as i128 * 5;
-        
-        // Volume rewards: 0.1% of total amount transacted
-        let volume_rewards = rewards.total_amount_transacted / 1000;
-        
-        // Total rewards
-        let total_rewards = creation_rewards + participation_rewards + volume_rewards;
-        
-        // Update rewards earned
-        let mut updated_rewards = rewards;
-        updated_rewards.rewards_earned = total_rewards;
-        storage::set_user_rewards(&env, &user, &updated_rewards);
+        let mut split = Self::get_editable_split(&env, split_id)?;
 
-        // Calculate available rewards (earned - claimed)
-        let available_rewards = total_rewards - rewards.rewards_claimed;
+        if share <= 0 {
+            return Err(Error::InvalidAmount);
+        }
 
-        // Emit rewards calculated event
-        events::emit_rewards_calculated(&env, &user, total_rewards, available_rewards);
+        if Self::find_participant(&split, &participant).is_some() {
+            return Err(Error::InvalidParticipantChange);
+        }
+
+        // Keep the split small enough for cancel_split to refund in one go
+        if split.participants.len() >= MAX_CANCEL_REFUNDS {
+            return Err(Error::InvalidParticipantChange);
+        }
+
+        split.participants.push_back(Participant {
+            address: participant.clone(),
+            share_amount: share,
+            amount_paid: 0,
+            has_paid: false,
+            amount_refunded: 0,
+        });
+        split.total_amount += share;
+        storage::set_split(&env, split_id, &split);
+
+        events::emit_participant_added(&env, split_id, &participant, share, split.total_amount);
 
-        total_rewards
+        Ok(())
     }
 
-    /// Claim rewards for a user
+    /// Remove a participant from a split that's still collecting
     ///
-    /// This function allows users to claim their earned rewards.
-    pub fn claim_rewards(
-        env: Env,
-        user: Address,
-    ) -> Result<i128, Error> {
-        // Get caller's address (require_auth for the caller)
-        let caller = env.current_contract_address();
-        caller.require_auth();
-
-        // Ensure caller is claiming their own rewards
-        if caller != user {
-            return Err(Error::UserNotFound);
+    /// I'm refunding their part of what the contract still holds and taking
+    /// their share off the split's total. If `release_partial` has already
+    /// sent some deposits on, they get back the same fraction of their
+    /// payment that `refund_allocations` would give them. If the
+    /// participants left have all paid, the split is released just as the
+    /// last deposit would have done. Returns the amount refunded. Like the
+    /// other refunds, this still works while deposits are paused.
+    pub fn remove_participant(env: Env, split_id: u64, participant: Address) -> Result<i128, Error> {
+        let mut split = Self::get_editable_split(&env, split_id)?;
+
+        let index = Self::find_participant(&split, &participant).ok_or(Error::ParticipantNotFound)?;
+        if split.participants.len() == 1 {
+            return Err(Error::InvalidParticipantChange);
         }
 
-        // Get user rewards data
-        let mut rewards = storage::get_user_rewards(&env, &user)
-            .ok_or(Error::UserNotFound)?;
+        let removed = split.participants.get(index).unwrap();
+        let held = split.amount_collected - split.amount_released;
+        let refund = if split.amount_collected > 0 {
+            removed.amount_paid * held / split.amount_collected
+        } else {
+            0
+        };
 
-        // Check if user is eligible for rewards
-        if rewards.status != types::RewardsStatus::Active {
-            return Err(Error::RewardsAlreadyClaimed);
+        if refund > 0 {
+            let token_client = token::Client::new(&env, &split.token);
+            token_client.transfer(&env.current_contract_address(), &participant, &refund);
         }
 
-        // Calculate available rewards
-        let available_rewards = rewards.rewards_earned - rewards.rewards_claimed;
-        
-        if available_rewards <= 0 {
-            return Err(Error::InsufficientRewards);
+        // Take their whole payment off the books, including the part
+        // already sent on, so collected less released is still what the
+        // contract holds for the split
+        split.participants.remove(index);
+        split.total_amount -= removed.share_amount;
+        split.amount_collected -= removed.amount_paid;
+        split.amount_released -= removed.amount_paid - refund;
+        if split.amount_collected == 0 {
+            split.status = SplitStatus::Pending;
         }
+        storage::set_split(&env, split_id, &split);
 
-        // Update claimed rewards
-        rewards.rewards_claimed += available_rewards;
-        rewards.last_activity = env.ledger().timestamp();
-        
-        // Store updated rewards
-        storage::set_user_rewards(&env, &user, &rewards);
-
-        // Note: In a real implementation, you would transfer tokens here
-        // For now, we'll just emit the event
+        events::emit_participant_removed(&env, split_id, &participant, refund, split.total_amount);
 
-        // Emit rewards claimed event
-        events::emit_rewards_claimed(&env, &user, available_rewards);
+        Self::release_if_funded(&env, split_id, split);
 
-        Ok(available_rewards)
+        Ok(refund)
     }
 
-    /// Submit verification for a split
+    /// Change what a participant owes on a split that's still collecting
     ///
-    /// This function allows users to submit verification requests with evidence.
-    pub fn submit_verification(
-        env: Env,
-        split_id: String,
-        receipt_hash: String,
-    ) -> Result<String, Error> {
-        // Get caller's address (require_auth for the caller)
-        let caller = env.current_contract_address();
-        caller.require_auth();
+    /// Only participants who haven't paid in full can have their share
+    /// changed, and never to less than they've already paid. The split's
+    /// total moves by the same amount.
+    pub fn update_share(env: Env, split_id: u64, participant: Address, new_share: i128) -> Result<(), Error> {
+        Self::require_not_paused(&env, PauseScope::Deposits)?;
 
-        // Check if split exists
-        let split_id_num = {
-            let mut result = 0u64;
-            let chars = split_id.clone();
-            for i in 0..chars.len() {
-                let char_val = chars.get(i).unwrap();
-                match char_val {
-                    '0' => result = result * 10 + 0,
-                    '1' => result = result * 10 + 1,
-                    '2' => result = result * 10 + 2,
-                    '3' => result = result * 10 + 3,
-                    '4' => result = result * 10 + 4,
-                    '5' => result = result * 10 + 5,
-                    '6' => result = result * 10 + 6,
-                    '7' => result = result * 10 + 7,
-                    '8' => result = result * 10 + 8,
-                    '9' => result = result * 10 + 9,
-                    _ => {} // Skip non-digit characters
-                }
-            }
-            result
-        };
+        let mut split = Self::get_editable_split(&env, split_id)?;
 
-        if !storage::has_split(&env, split_id_num) {
-            return Err(Error::SplitNotFound);
+        if new_share <= 0 {
+            return Err(Error::InvalidAmount);
         }
 
-        // Check if verification already exists
-        if storage::has_verification_request(&env, &split_id) {
-            return Err(Error::VerificationAlreadyExists);
+        let index = Self::find_participant(&split, &participant).ok_or(Error::ParticipantNotFound)?;
+        let mut p = split.participants.get(index).unwrap();
+        if p.has_paid {
+            return Err(Error::InvalidParticipantChange);
+        }
+        if new_share < p.amount_paid {
+            return Err(Error::ExceedsOwed);
         }
 
-        // Generate verification ID
-        let verification_id = storage::get_next_verification_id(&env);
+        let old_share = p.share_amount;
+        p.share_amount = new_share;
+        p.has_paid = p.amount_paid >= new_share;
+        split.participants.set(index, p);
+        split.total_amount += new_share - old_share;
+        storage::set_split(&env, split_id, &split);
 
-        // Create verification request
-        let request = types::VerificationRequest {
-            verification_id: verification_id.clone(),
-            split_id: split_id.clone(),
-            requester: caller,
-            receipt_hash: receipt_hash.clone(),
-            evidence_url: None,
-            submitted_at: env.ledger().timestamp(),
-            status: types::VerificationStatus::Pending,
+        events::emit_share_updated(&env, split_id, &participant, old_share, new_share, split.total_amount);
+
+        Self::release_if_funded(&env, split_id, split);
+
+        Ok(())
+    }
+
+    /// Get split details by ID
+    pub fn get_split(env: Env, split_id: u64) -> Result<Split, Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        Ok(storage::get_split(&env, split_id))
+    }
+
+    /// Get who a split's releases are paid to, and their weights
+    ///
+    /// Splits created without a recipient list pay everything to the
+    /// creator.
+    pub fn get_split_recipients(env: Env, split_id: u64) -> Result<Vec<Recipient>, Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let split = storage::get_split(&env, split_id);
+        Ok(Self::split_recipients(&env, &split))
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Address {
+        storage::get_admin(&env)
+    }
+
+    /// Get the token contract address
+    pub fn get_token(env: Env) -> Address {
+        storage::get_token(&env)
+    }
+
+    /// Let new splits be created in another token
+    ///
+    /// Admin only. The default token is always allowed.
+    pub fn allow_token(env: Env, token: Address) -> Result<(), Error> {
+        Self::require_admin(&env, "allow_token", (token.clone(),).into_val(&env))?;
+
+        if storage::is_token_allowed(&env, &token) {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut tokens = storage::get_allowed_tokens(&env);
+        tokens.push_back(token.clone());
+        storage::set_allowed_tokens(&env, &tokens);
+
+        events::emit_token_allowlist_updated(&env, &token, true);
+
+        Ok(())
+    }
+
+    /// Stop new splits being created in a token
+    ///
+    /// Admin only. Splits already using the token carry on with it.
+    pub fn disallow_token(env: Env, token: Address) -> Result<(), Error> {
+        Self::require_admin(&env, "disallow_token", (token.clone(),).into_val(&env))?;
+
+        let mut tokens = storage::get_allowed_tokens(&env);
+        let index = tokens.first_index_of(&token).ok_or(Error::TokenNotAllowed)?;
+        tokens.remove(index);
+        storage::set_allowed_tokens(&env, &tokens);
+
+        events::emit_token_allowlist_updated(&env, &token, false);
+
+        Ok(())
+    }
+
+    /// Get every token new splits may use, starting with the default
+    pub fn get_allowed_tokens(env: Env) -> Vec<Address> {
+        let mut tokens = Vec::from_array(&env, [storage::get_token(&env)]);
+        tokens.append(&storage::get_allowed_tokens(&env));
+        tokens
+    }
+
+    /// Get the admin and global pause flag
+    pub fn get_config(env: Env) -> ContractConfig {
+        ContractConfig {
+            admin: storage::get_admin(&env),
+            is_paused: storage::is_paused(&env, PauseScope::All),
+        }
+    }
+
+    /// Pause the whole contract or one part of it
+    ///
+    /// Admin only. I'm leaving cancellation, expiry and refunds open during a
+    /// pause so participants can always get their money back. Pausing never
+    /// waits on the admin council, so an emergency stop isn't held up.
+    pub fn pause(env: Env, scope: PauseScope) {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        storage::set_paused(&env, scope, true);
+
+        events::emit_paused(&env, scope);
+    }
+
+    /// Lift a pause set by `pause`
+    ///
+    /// Admin only. Each scope is lifted on its own, so unpausing `All`
+    /// leaves a separate `Deposits` pause in place.
+    pub fn unpause(env: Env, scope: PauseScope) -> Result<(), Error> {
+        Self::require_admin(&env, "unpause", (scope,).into_val(&env))?;
+
+        storage::set_paused(&env, scope, false);
+
+        events::emit_unpaused(&env, scope);
+
+        Ok(())
+    }
+
+    /// Check whether calls in a scope are currently rejected
+    pub fn is_paused(env: Env, scope: PauseScope) -> bool {
+        storage::is_paused(&env, PauseScope::All) || storage::is_paused(&env, scope)
+    }
+
+    // ============================================
+    // Admin Rotation
+    // ============================================
+
+    /// Propose a new admin
+    ///
+    /// Admin only. Nothing changes until `new_admin` calls `accept_admin`,
+    /// so a mistyped address can't strand the contract. A new proposal
+    /// replaces any pending one.
+    pub fn propose_admin(
+        env: Env,
+        new_admin: Address,
+        expires_at: Option<u64>,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, "propose_admin", (new_admin.clone(), expires_at).into_val(&env))?;
+
+        let now = env.ledger().timestamp();
+        if let Some(expires_at) = expires_at {
+            if expires_at <= now {
+                return Err(Error::InvalidConfig);
+            }
+        }
+
+        storage::set_pending_admin(
+            &env,
+            &AdminProposal {
+                proposed_admin: new_admin.clone(),
+                proposed_at: now,
+                expires_at,
+            },
+        );
+
+        events::emit_admin_proposed(&env, &storage::get_admin(&env), &new_admin, expires_at);
+
+        Ok(())
+    }
+
+    /// Accept a pending admin proposal
+    ///
+    /// Must be signed by the proposed admin before the proposal expires.
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        let proposal = storage::get_pending_admin(&env).ok_or(Error::NoPendingAdmin)?;
+
+        if let Some(expires_at) = proposal.expires_at {
+            if env.ledger().timestamp() > expires_at {
+                return Err(Error::NoPendingAdmin);
+            }
+        }
+
+        proposal.proposed_admin.require_auth();
+
+        let old_admin = storage::get_admin(&env);
+        storage::set_admin(&env, &proposal.proposed_admin);
+        storage::remove_pending_admin(&env);
+
+        events::emit_admin_changed(&env, &old_admin, &proposal.proposed_admin);
+
+        Ok(())
+    }
+
+    /// Withdraw a pending admin proposal
+    pub fn cancel_admin_proposal(env: Env) -> Result<(), Error> {
+        Self::require_admin(&env, "cancel_admin_proposal", ().into_val(&env))?;
+
+        let proposal = storage::get_pending_admin(&env).ok_or(Error::NoPendingAdmin)?;
+        storage::remove_pending_admin(&env);
+
+        events::emit_admin_proposal_cancelled(&env, &proposal.proposed_admin);
+
+        Ok(())
+    }
+
+    /// Get the pending admin proposal, if any
+    pub fn get_pending_admin(env: Env) -> Option<AdminProposal> {
+        storage::get_pending_admin(&env)
+    }
+
+    /// Require `threshold` of `signers` to approve config changes
+    ///
+    /// Admin only, and once a council exists it must approve changes to
+    /// itself too. An empty signer list with a zero threshold removes it.
+    pub fn set_admin_council(env: Env, signers: Vec<Address>, threshold: u32) -> Result<(), Error> {
+        Self::require_admin(
+            &env,
+            "set_admin_council",
+            (signers.clone(), threshold).into_val(&env),
+        )?;
+
+        if signers.is_empty() && threshold == 0 {
+            storage::remove_admin_council(&env);
+        } else {
+            let council = AdminCouncil {
+                signers: signers.clone(),
+                threshold,
+            };
+            if !council.is_valid() {
+                return Err(Error::InvalidConfig);
+            }
+            storage::set_admin_council(&env, &council);
+        }
+
+        events::emit_admin_council_updated(&env, &signers, threshold);
+
+        Ok(())
+    }
+
+    /// Get the admin council, if one is set
+    pub fn get_admin_council(env: Env) -> Option<AdminCouncil> {
+        storage::get_admin_council(&env)
+    }
+
+    /// Approve one admin config change as a council signer
+    ///
+    /// `action_hash` comes from `admin_action_hash` for the exact call the
+    /// admin will make. Returns the number of approvals so far.
+    pub fn approve_admin_action(
+        env: Env,
+        signer: Address,
+        action_hash: BytesN<32>,
+    ) -> Result<u32, Error> {
+        signer.require_auth();
+
+        let council = storage::get_admin_council(&env).ok_or(Error::Unauthorized)?;
+        if !council.signers.contains(&signer) {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut approvals = storage::get_admin_approvals(&env, &action_hash);
+        if approvals.contains(&signer) {
+            return Err(Error::AlreadyAttested);
+        }
+
+        approvals.push_back(signer.clone());
+        storage::set_admin_approvals(&env, &action_hash, &approvals);
+
+        events::emit_admin_action_approved(&env, &signer, &action_hash, approvals.len());
+
+        Ok(approvals.len())
+    }
+
+    /// Hash an admin call for council approval
+    ///
+    /// `action` is the entrypoint name and `args` its arguments, in order.
+    pub fn admin_action_hash(env: Env, action: Symbol, args: Vec<Val>) -> BytesN<32> {
+        env.crypto().sha256(&(action, args).to_xdr(&env)).to_bytes()
+    }
+
+    // ============================================
+    // Platform Fees
+    // ============================================
+
+    /// Set the platform fee taken from split releases
+    ///
+    /// Admin only. Fees are off until this is first called; setting a zero
+    /// rate and minimum turns them off again while keeping the treasury for
+    /// withdrawals.
+    pub fn set_fee_config(env: Env, config: FeeConfig) -> Result<(), Error> {
+        Self::require_admin(&env, "set_fee_config", (config.clone(),).into_val(&env))?;
+
+        if !config.is_valid() {
+            return Err(Error::InvalidConfig);
+        }
+
+        storage::set_fee_config(&env, &config);
+
+        events::emit_fee_config_updated(&env, &config);
+
+        Ok(())
+    }
+
+    /// Get the platform fee config, if fees are switched on
+    pub fn get_fee_config(env: Env) -> Option<FeeConfig> {
+        storage::get_fee_config(&env)
+    }
+
+    /// Set the minimum and maximum fee for releases in a token
+    ///
+    /// Admin only. The config's own `min_fee` and `max_fee` are in the
+    /// default token, so other tokens have neither until this sets them.
+    /// Bounds set for the default token take over from the config's.
+    pub fn set_fee_bounds(env: Env, token: Address, bounds: FeeBounds) -> Result<(), Error> {
+        Self::require_admin(&env, "set_fee_bounds", (token.clone(), bounds.clone()).into_val(&env))?;
+
+        if !bounds.is_valid() {
+            return Err(Error::InvalidConfig);
+        }
+
+        storage::set_fee_bounds(&env, &token, &bounds);
+
+        events::emit_fee_bounds_updated(&env, &token, &bounds);
+
+        Ok(())
+    }
+
+    /// Get the fee bounds releases in a token are charged within
+    pub fn get_fee_bounds(env: Env, token: Address) -> FeeBounds {
+        match storage::get_fee_config(&env) {
+            Some(config) => Self::fee_bounds(&env, &config, &token),
+            None => storage::get_fee_bounds(&env, &token).unwrap_or(FeeBounds::none()),
+        }
+    }
+
+    /// Exempt a split creator from platform fees, or end the exemption
+    pub fn set_fee_exempt(env: Env, address: Address, exempt: bool) -> Result<(), Error> {
+        Self::require_admin(&env, "set_fee_exempt", (address.clone(), exempt).into_val(&env))?;
+
+        storage::set_fee_exempt(&env, &address, exempt);
+
+        events::emit_fee_exemption_updated(&env, &address, exempt);
+
+        Ok(())
+    }
+
+    /// Check whether an address is exempt from platform fees
+    pub fn is_fee_exempt(env: Env, address: Address) -> bool {
+        storage::is_fee_exempt(&env, &address)
+    }
+
+    /// Get the fees collected in a token and not yet withdrawn
+    pub fn get_fee_balance(env: Env, token: Address) -> i128 {
+        storage::get_fee_balance(&env, &token)
+    }
+
+    /// Send the fees collected in a token to the fee treasury
+    ///
+    /// Admin only. Returns the amount sent.
+    pub fn withdraw_fees(env: Env, token: Address) -> Result<i128, Error> {
+        Self::require_admin(&env, "withdraw_fees", (token.clone(),).into_val(&env))?;
+
+        let config = storage::get_fee_config(&env).ok_or(Error::InvalidConfig)?;
+
+        let amount = storage::get_fee_balance(&env, &token);
+        if amount <= 0 {
+            return Err(Error::NoFundsAvailable);
+        }
+
+        storage::set_fee_balance(&env, &token, 0);
+
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&env.current_contract_address(), &config.treasury, &amount);
+
+        events::emit_fees_withdrawn(&env, &token, &config.treasury, amount);
+
+        Ok(amount)
+    }
+
+    // ============================================
+    // Upgrades
+    // ============================================
+
+    /// Replace the contract's code with an uploaded WASM
+    ///
+    /// Storage is kept as is. If the new code changes a stored layout, the
+    /// admin follows up with `migrate` before using the contract again.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::require_admin(&env, "upgrade", (new_wasm_hash.clone(),).into_val(&env))?;
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        events::emit_contract_upgraded(&env, &new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Get the layout version of the stored records
+    pub fn get_schema_version(env: Env) -> u32 {
+        storage::get_schema_version(&env)
+    }
+
+    /// Rewrite records stored by an older version of the contract
+    ///
+    /// I'm walking the splits `batch_size` IDs per call so a contract with
+    /// many splits doesn't run out of budget. Returns `true` once every
+    /// split is on the current layout; until then, call it again. With an
+    /// admin council, each batch needs its own approval. Verification
+    /// requests don't need a pass of their own, since older ones are
+    /// upgraded whenever they're read.
+    pub fn migrate(env: Env, batch_size: u32) -> Result<bool, Error> {
+        Self::require_admin(&env, "migrate", (batch_size,).into_val(&env))?;
+
+        if batch_size == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let from_version = storage::get_schema_version(&env);
+        if from_version >= SCHEMA_VERSION {
+            return Ok(true);
+        }
+
+        let split_count = storage::get_split_count(&env);
+        let mut cursor = storage::get_migration_cursor(&env);
+        let end = cursor.saturating_add(batch_size as u64);
+
+        while cursor < end && cursor <= split_count {
+            storage::migrate_split(&env, cursor);
+            cursor += 1;
+        }
+
+        if cursor > split_count {
+            storage::set_schema_version(&env, SCHEMA_VERSION);
+            storage::remove_migration_cursor(&env);
+            events::emit_schema_migrated(&env, from_version, SCHEMA_VERSION);
+            return Ok(true);
+        }
+
+        storage::set_migration_cursor(&env, cursor);
+        Ok(false)
+    }
+
+    // ============================================
+    // Escrow Lifecycle Functions (Issue #59)
+    // ============================================
+
+    /// Create a string-keyed escrow
+    ///
+    /// I'm letting the caller supply the split ID so the backend can reuse
+    /// its own UUIDs; when none is given the contract generates one.
+    pub fn create_escrow(
+        env: Env,
+        creator: Address,
+        split_id: Option<String>,
+        description: String,
+        total_amount: i128,
+        participant_addresses: Vec<Address>,
+        participant_amounts: Vec<i128>,
+        deadline: u64,
+    ) -> Result<String, Error> {
+        creator.require_auth();
+
+        Self::require_not_paused(&env, PauseScope::Deposits)?;
+
+        if participant_addresses.len() != participant_amounts.len()
+            || participant_addresses.is_empty()
+        {
+            return Err(Error::InvalidEscrow);
+        }
+
+        if deadline <= env.ledger().timestamp() {
+            return Err(Error::InvalidEscrow);
+        }
+
+        let split_id = match split_id {
+            Some(id) => id,
+            None => storage::generate_escrow_id(&env, &creator),
+        };
+
+        if storage::has_escrow(&env, &split_id) {
+            return Err(Error::EscrowAlreadyExists);
+        }
+
+        let mut participants = Vec::new(&env);
+        for i in 0..participant_addresses.len() {
+            let participant = EscrowParticipant::new(
+                participant_addresses.get(i).unwrap(),
+                participant_amounts.get(i).unwrap(),
+            );
+            participant.validate().map_err(|_| Error::InvalidEscrow)?;
+            participants.push_back(participant);
+        }
+
+        let escrow = types::create_escrow(
+            &env,
+            split_id.clone(),
+            creator.clone(),
+            description,
+            total_amount,
+            participants,
+            deadline,
+        );
+
+        escrow.validate().map_err(|_| Error::InvalidEscrow)?;
+        storage::set_escrow(&env, &split_id, &escrow);
+
+        events::emit_escrow_created(&env, &split_id, &creator, total_amount);
+
+        Ok(split_id)
+    }
+
+    /// Pay into an escrow
+    ///
+    /// I'm completing the escrow and releasing funds to the creator as soon
+    /// as the final payment lands, mirroring the auto-release in `deposit`.
+    pub fn pay_escrow(
+        env: Env,
+        split_id: String,
+        participant: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        participant.require_auth();
+
+        Self::require_not_paused(&env, PauseScope::Deposits)?;
+
+        let mut escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        let now = env.ledger().timestamp();
+        if escrow.is_expired(now) {
+            return Err(Error::SplitExpired);
+        }
+
+        let mut index = None;
+        for i in 0..escrow.participants.len() {
+            if escrow.participants.get(i).unwrap().address == participant {
+                index = Some(i);
+                break;
+            }
+        }
+        let index = index.ok_or(Error::ParticipantNotFound)?;
+
+        let mut p = escrow.participants.get(index).unwrap();
+        if amount > p.remaining_owed() {
+            return Err(Error::ExceedsOwed);
+        }
+
+        // The last payment releases the escrow, so it has to wait out a
+        // pause on releases
+        if escrow.amount_collected + amount >= escrow.total_amount {
+            Self::require_not_paused(&env, PauseScope::Releases)?;
+        }
+
+        let token_address = storage::get_token(&env);
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&participant, &env.current_contract_address(), &amount);
+
+        p.amount_paid += amount;
+        if p.has_fully_paid() {
+            p.paid_at = Some(now);
+        }
+        escrow.participants.set(index, p);
+        escrow.amount_collected += amount;
+        storage::add_participant_payment(&env, &split_id, &participant, amount);
+
+        events::emit_payment_received(&env, &split_id, &participant, amount);
+
+        if escrow.is_fully_funded() {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &escrow.creator,
+                &escrow.amount_collected,
+            );
+            escrow.status = EscrowStatus::Completed;
+            events::emit_escrow_released(
+                &env,
+                &split_id,
+                &escrow.creator,
+                escrow.amount_collected,
+            );
+        }
+
+        escrow.validate().map_err(|_| Error::InvalidEscrow)?;
+        storage::set_escrow(&env, &split_id, &escrow);
+
+        Ok(())
+    }
+
+    /// Cancel an active escrow and refund payments
+    ///
+    /// Only the creator can cancel. Refunds follow the same push/pull rule
+    /// as `cancel_split`.
+    pub fn cancel_escrow(env: Env, split_id: String) -> Result<(), Error> {
+        let mut escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        escrow.creator.require_auth();
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        escrow.status = EscrowStatus::Cancelled;
+        events::emit_escrow_cancelled(&env, &split_id);
+
+        Self::open_escrow_refunds(&env, &mut escrow);
+
+        escrow.validate().map_err(|_| Error::InvalidEscrow)?;
+        storage::set_escrow(&env, &split_id, &escrow);
+
+        Ok(())
+    }
+
+    /// Expire an escrow whose deadline has passed
+    ///
+    /// Permissionless, like `expire_split`.
+    pub fn expire_escrow(env: Env, split_id: String) -> Result<(), Error> {
+        let mut escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        if !escrow.is_expired(env.ledger().timestamp()) {
+            return Err(Error::SplitNotExpired);
+        }
+
+        escrow.status = EscrowStatus::Expired;
+        events::emit_escrow_expired(&env, &split_id);
+
+        Self::open_escrow_refunds(&env, &mut escrow);
+
+        escrow.validate().map_err(|_| Error::InvalidEscrow)?;
+        storage::set_escrow(&env, &split_id, &escrow);
+
+        Ok(())
+    }
+
+    /// Claim a refund from a cancelled or expired escrow
+    pub fn claim_escrow_refund(
+        env: Env,
+        split_id: String,
+        participant: Address,
+    ) -> Result<i128, Error> {
+        participant.require_auth();
+
+        let mut escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        if escrow.status != EscrowStatus::Cancelled && escrow.status != EscrowStatus::Expired {
+            return Err(Error::RefundsNotOpen);
+        }
+
+        let mut index = None;
+        for i in 0..escrow.participants.len() {
+            if escrow.participants.get(i).unwrap().address == participant {
+                index = Some(i);
+                break;
+            }
+        }
+        let index = index.ok_or(Error::ParticipantNotFound)?;
+
+        let refunded = Self::refund_escrow_participant(&env, &mut escrow, index);
+        if refunded == 0 {
+            return Err(Error::NoFundsAvailable);
+        }
+
+        escrow.validate().map_err(|_| Error::InvalidEscrow)?;
+        storage::set_escrow(&env, &split_id, &escrow);
+
+        Ok(refunded)
+    }
+
+    /// Get an escrow by split ID
+    pub fn get_escrow(env: Env, split_id: String) -> Result<SplitEscrow, Error> {
+        storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)
+    }
+
+    // ============================================
+    // Insurance Functions
+    // ============================================
+
+    /// Buy insurance for a split
+    ///
+    /// I'm collecting the premium in the split's token and paying it into
+    /// that token's insurance pool. Coverage is a multiple of the premium,
+    /// capped at the split's total so nobody can insure more than is
+    /// actually at stake.
+    pub fn insure_split(
+        env: Env,
+        split_id: String,
+        policy_holder: Address,
+        premium: i128,
+    ) -> Result<String, Error> {
+        policy_holder.require_auth();
+
+        Self::require_not_paused(&env, PauseScope::Insurance)?;
+
+        if premium <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let split_id_num = format::parse_u64(&split_id).ok_or(Error::SplitNotFound)?;
+        if !storage::has_split(&env, split_id_num) {
+            return Err(Error::SplitNotFound);
+        }
+
+        // "01" parses to the same split as "1", so the policy is keyed on
+        // the canonical form or aliases could insure one split many times
+        let split_id = format::u64_to_string(&env, split_id_num);
+        if storage::has_split_insurance(&env, &split_id) {
+            return Err(Error::InsuranceAlreadyExists);
+        }
+
+        let split = storage::get_split(&env, split_id_num);
+        let coverage_amount = core::cmp::min(
+            premium
+                .checked_mul(INSURANCE_COVERAGE_MULTIPLIER)
+                .ok_or(Error::InvalidAmount)?,
+            split.total_amount,
+        );
+
+        // Refuse policies the pool couldn't stand behind
+        let mut pool = storage::get_insurance_pool(&env, &split.token);
+        if !pool.can_cover(premium, coverage_amount) {
+            return Err(Error::InsufficientPoolReserves);
+        }
+
+        let token_client = token::Client::new(&env, &split.token);
+        token_client.transfer(&policy_holder, &env.current_contract_address(), &premium);
+
+        pool.reserves += premium;
+        pool.total_premiums += premium;
+        pool.total_exposure += coverage_amount;
+        storage::set_insurance_pool(&env, &split.token, &pool);
+
+        let now = env.ledger().timestamp();
+        let insurance_id = storage::generate_insurance_id(&env);
+        let policy = types::InsurancePolicy {
+            insurance_id: insurance_id.clone(),
+            split_id: split_id.clone(),
+            policy_holder: policy_holder.clone(),
+            premium,
+            coverage_amount,
+            status: types::InsuranceStatus::Active,
+            created_at: now,
+            expires_at: now + INSURANCE_DURATION,
+        };
+
+        storage::set_insurance(&env, &insurance_id, &policy);
+        storage::set_split_to_insurance(&env, &split_id, &insurance_id);
+
+        events::emit_insurance_purchased(
+            &env,
+            &insurance_id,
+            &split_id,
+            &policy_holder,
+            premium,
+            coverage_amount,
+        );
+
+        Ok(insurance_id)
+    }
+
+    /// File a claim against an insurance policy
+    ///
+    /// Only the policy holder can claim, only while the policy is active,
+    /// and never for more than the coverage amount.
+    pub fn claim_insurance(
+        env: Env,
+        insurance_id: String,
+        reason: String,
+        claim_amount: i128,
+    ) -> Result<String, Error> {
+        Self::require_not_paused(&env, PauseScope::Insurance)?;
+
+        if !storage::has_insurance(&env, &insurance_id) {
+            return Err(Error::InsuranceNotFound);
+        }
+
+        let policy = storage::get_insurance(&env, &insurance_id);
+        policy.policy_holder.require_auth();
+
+        if policy.status != types::InsuranceStatus::Active {
+            return Err(Error::InsuranceNotActive);
+        }
+
+        let now = env.ledger().timestamp();
+        if now > policy.expires_at {
+            return Err(Error::InsuranceExpired);
+        }
+
+        if claim_amount <= 0 || claim_amount > policy.coverage_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        let claim_id = storage::generate_claim_id(&env);
+        let claim = types::InsuranceClaim {
+            claim_id: claim_id.clone(),
+            insurance_id: insurance_id.clone(),
+            claimant: policy.policy_holder.clone(),
+            reason,
+            claim_amount,
+            status: types::ClaimStatus::Pending,
+            filed_at: now,
+            processed_at: None,
+            notes: None,
+        };
+
+        storage::set_claim(&env, &claim_id, &claim);
+        storage::add_insurance_claim(&env, &insurance_id, &claim_id);
+
+        events::emit_claim_filed(&env, &claim_id, &insurance_id, &policy.policy_holder, claim_amount);
+
+        Ok(claim_id)
+    }
+
+    /// Approve or reject a pending insurance claim
+    ///
+    /// I'm letting either the admin or a configured oracle adjudicate.
+    /// Approved claims are paid straight out of the insurance pool for the
+    /// split's token and the policy is marked as claimed. Claims filed before expiry are still
+    /// honoured if they are processed afterwards.
+    pub fn process_claim(
+        env: Env,
+        claim_id: String,
+        adjudicator: Address,
+        approved: bool,
+    ) -> Result<(), Error> {
+        adjudicator.require_auth();
+
+        Self::require_not_paused(&env, PauseScope::Insurance)?;
+
+        let is_admin = adjudicator == storage::get_admin(&env);
+        let is_oracle = storage::get_oracle_config(&env)
+            .map(|config| config.oracle_addresses.contains(&adjudicator))
+            .unwrap_or(false);
+        if !is_admin && !is_oracle {
+            return Err(Error::Unauthorized);
+        }
+
+        if !storage::has_claim(&env, &claim_id) {
+            return Err(Error::ClaimNotFound);
+        }
+
+        let mut claim = storage::get_claim(&env, &claim_id);
+        if claim.status != types::ClaimStatus::Pending {
+            return Err(Error::InvalidClaimStatus);
+        }
+
+        let mut policy = storage::get_insurance(&env, &claim.insurance_id);
+        if policy.status != types::InsuranceStatus::Active {
+            return Err(Error::InsuranceNotActive);
+        }
+
+        claim.processed_at = Some(env.ledger().timestamp());
+
+        let payout = if approved {
+            let token_address = Self::policy_token(&env, &policy);
+            let mut pool = storage::get_insurance_pool(&env, &token_address);
+            if pool.reserves < claim.claim_amount {
+                return Err(Error::InsufficientPoolFunds);
+            }
+
+            let token_client = token::Client::new(&env, &token_address);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &claim.claimant,
+                &claim.claim_amount,
+            );
+
+            // The policy is used up, so its whole coverage leaves the books
+            pool.reserves -= claim.claim_amount;
+            pool.total_payouts += claim.claim_amount;
+            pool.total_exposure -= policy.coverage_amount;
+            storage::set_insurance_pool(&env, &token_address, &pool);
+
+            claim.status = types::ClaimStatus::Paid;
+            policy.status = types::InsuranceStatus::Claimed;
+            storage::set_insurance(&env, &claim.insurance_id, &policy);
+
+            claim.claim_amount
+        } else {
+            claim.status = types::ClaimStatus::Rejected;
+            0
+        };
+
+        storage::set_claim(&env, &claim_id, &claim);
+
+        events::emit_claim_processed(&env, &claim_id, &claim.insurance_id, approved, payout);
+        if approved {
+            events::emit_payout_made(&env, &claim_id, &claim.claimant, payout);
+        }
+
+        Ok(())
+    }
+
+    /// Expire a policy that has passed `expires_at`
+    ///
+    /// Permissionless housekeeping: it releases the policy's coverage from
+    /// the pool's exposure so the capacity can back new policies.
+    pub fn expire_insurance(env: Env, insurance_id: String) -> Result<(), Error> {
+        if !storage::has_insurance(&env, &insurance_id) {
+            return Err(Error::InsuranceNotFound);
+        }
+
+        let mut policy = storage::get_insurance(&env, &insurance_id);
+        if policy.status != types::InsuranceStatus::Active {
+            return Err(Error::InsuranceNotActive);
+        }
+
+        if env.ledger().timestamp() <= policy.expires_at {
+            return Err(Error::InsuranceNotExpired);
+        }
+
+        // Pending claims filed in time keep the policy alive until processed
+        for claim_id in storage::get_insurance_claims(&env, &insurance_id).iter() {
+            if storage::get_claim(&env, &claim_id).status == types::ClaimStatus::Pending {
+                return Err(Error::InvalidClaimStatus);
+            }
+        }
+
+        policy.status = types::InsuranceStatus::Expired;
+        storage::set_insurance(&env, &insurance_id, &policy);
+
+        let token = Self::policy_token(&env, &policy);
+        let mut pool = storage::get_insurance_pool(&env, &token);
+        pool.total_exposure -= policy.coverage_amount;
+        storage::set_insurance_pool(&env, &token, &pool);
+
+        Ok(())
+    }
+
+    /// Add capital to a token's insurance pool
+    ///
+    /// The admin or any liquidity provider can top up reserves. I'm recording
+    /// each provider's running contribution for later accounting. Each
+    /// token has its own pool, which only backs splits in that token.
+    pub fn deposit_pool_capital(env: Env, provider: Address, token: Address, amount: i128) -> Result<i128, Error> {
+        provider.require_auth();
+
+        Self::require_not_paused(&env, PauseScope::Insurance)?;
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&provider, &env.current_contract_address(), &amount);
+
+        let mut pool = storage::get_insurance_pool(&env, &token);
+        pool.reserves += amount;
+        pool.total_capital += amount;
+        storage::set_insurance_pool(&env, &token, &pool);
+
+        let contributed = storage::add_pool_provider_capital(&env, &provider, &token, amount);
+
+        events::emit_pool_capital_deposited(&env, &provider, &token, amount, pool.reserves);
+
+        Ok(contributed)
+    }
+
+    /// Set the minimum reserve ratio for new policies in a token
+    ///
+    /// Admin only. Expressed in basis points, so 10_000 means every unit of
+    /// outstanding coverage must be backed by a unit of reserves.
+    pub fn set_min_reserve_ratio(env: Env, token: Address, min_reserve_ratio_bps: u32) -> Result<(), Error> {
+        Self::require_admin(
+            &env,
+            "set_min_reserve_ratio",
+            (token.clone(), min_reserve_ratio_bps).into_val(&env),
+        )?;
+
+        let mut pool = storage::get_insurance_pool(&env, &token);
+        pool.min_reserve_ratio_bps = min_reserve_ratio_bps;
+        storage::set_insurance_pool(&env, &token, &pool);
+
+        events::emit_reserve_ratio_updated(&env, &token, min_reserve_ratio_bps);
+
+        Ok(())
+    }
+
+    /// Get a token's insurance pool reserves, exposure and lifetime totals
+    pub fn get_pool_state(env: Env, token: Address) -> types::InsurancePool {
+        storage::get_insurance_pool(&env, &token)
+    }
+
+    /// Get the capital a liquidity provider has put into a token's pool
+    pub fn get_pool_provider_capital(env: Env, provider: Address, token: Address) -> i128 {
+        storage::get_pool_provider_capital(&env, &provider, &token)
+    }
+
+    /// Get insurance policy by ID
+    pub fn get_insurance(env: Env, insurance_id: String) -> types::InsurancePolicy {
+        storage::get_insurance(&env, &insurance_id)
+    }
+
+    /// Get insurance claim by ID
+    pub fn get_claim(env: Env, claim_id: String) -> types::InsuranceClaim {
+        storage::get_claim(&env, &claim_id)
+    }
+
+    /// Get all claims for an insurance policy
+    pub fn get_insurance_claims(env: Env, insurance_id: String) -> Vec<String> {
+        storage::get_insurance_claims(&env, &insurance_id)
+    }
+
+    /// Check if a split has insurance
+    pub fn has_split_insurance(env: Env, split_id: String) -> bool {
+        match format::parse_u64(&split_id) {
+            Some(split_id) => storage::has_split_insurance(&env, &format::u64_to_string(&env, split_id)),
+            None => false,
+        }
+    }
+
+    /// Get insurance ID for a split
+    pub fn get_split_insurance(env: Env, split_id: u64) -> Option<String> {
+        storage::get_split_to_insurance(&env, &format::u64_to_string(&env, split_id))
+    }
+
+    /// Track user split usage for rewards calculation
+    ///
+    /// Splits record their own activity internally, so this is only for
+    /// participation that happens off-contract. I'm restricting it to the
+    /// admin, since letting users report themselves would mint free rewards.
+    pub fn track_split_usage(
+        env: Env,
+        user: Address,
+    ) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        Self::require_not_paused(&env, PauseScope::Rewards)?;
+
+        // Splits record their own activity; this is for participation
+        // that happens outside the contract, so there's no split to cite
+        Self::record_activity(&env, &user, ActivityType::SplitParticipated, 0, &storage::get_token(&env), 0);
+
+        Ok(())
+    }
+
+    /// Calculate rewards for a user
+    ///
+    /// This function credits a user for activity since their last calculation,
+    /// priced with the current `RewardsConfig`, and returns their total earned.
+    /// Anything cut by a cap is forfeited, so only the user can choose when
+    /// to cash in their activity.
+    pub fn calculate_rewards(
+        env: Env,
+        user: Address,
+    ) -> Result<i128, Error> {
+        user.require_auth();
+
+        Self::require_not_paused(&env, PauseScope::Rewards)?;
+
+        // Get user rewards data
+        let mut rewards = storage::get_user_rewards(&env, &user)
+            .unwrap_or(types::UserRewards {
+                user: user.clone(),
+                total_splits_created: 0,
+                total_splits_participated: 0,
+                total_amount_transacted: 0,
+                rewards_earned: 0,
+                rewards_claimed: 0,
+                last_activity: env.ledger().timestamp(),
+                status: types::RewardsStatus::Active,
+            });
+
+        let config = storage::get_rewards_config(&env);
+        let now = env.ledger().timestamp();
+
+        // Only activity since the last calculation is priced, so rewards
+        // already earned keep the config they were earned under
+        let mut checkpoint = storage::get_rewards_checkpoint(&env, &user);
+        let mut accrued = config.activity_rewards(
+            rewards.total_splits_created - checkpoint.splits_created,
+            rewards.total_splits_participated - checkpoint.splits_participated,
+            rewards.total_amount_transacted - checkpoint.amount_transacted,
+        );
+
+        // Inactive users get less for activity they haven't cashed in
+        accrued = config.apply_decay(accrued, now.saturating_sub(rewards.last_activity));
+
+        if config.max_rewards_per_user > 0 {
+            let headroom = core::cmp::max(config.max_rewards_per_user - rewards.rewards_earned, 0);
+            accrued = core::cmp::min(accrued, headroom);
+        }
+
+        // A zero limit leaves the epochs uncapped, like the other caps
+        if config.epoch_emission_limit > 0 {
+            if let Some(epoch) = now.checked_div(config.epoch_duration) {
+                let emitted = storage::get_epoch_emissions(&env, epoch);
+                let headroom = core::cmp::max(config.epoch_emission_limit - emitted, 0);
+                accrued = core::cmp::min(accrued, headroom);
+                storage::set_epoch_emissions(&env, epoch, emitted + accrued);
+            }
+        }
+
+        // Anything cut by a cap is forfeited rather than carried forward
+        checkpoint.splits_created = rewards.total_splits_created;
+        checkpoint.splits_participated = rewards.total_splits_participated;
+        checkpoint.amount_transacted = rewards.total_amount_transacted;
+        storage::set_rewards_checkpoint(&env, &user, &checkpoint);
+
+        // Update rewards earned, keeping the treasury's liabilities in step
+        rewards.rewards_earned += accrued;
+        storage::adjust_rewards_liabilities(&env, accrued);
+        storage::set_user_rewards(&env, &user, &rewards);
+
+        // Calculate available rewards (earned - claimed)
+        let total_rewards = rewards.rewards_earned;
+        let available_rewards = total_rewards - rewards.rewards_claimed;
+
+        // Emit rewards calculated event
+        events::emit_rewards_calculated(&env, &user, total_rewards, available_rewards);
+
+        Ok(total_rewards)
+    }
+
+    /// Set the reward formula
+    ///
+    /// Admin only. The new config applies to activity from the next
+    /// `calculate_rewards` onwards; rewards already earned are untouched.
+    pub fn set_rewards_config(env: Env, config: types::RewardsConfig) -> Result<(), Error> {
+        Self::require_admin(&env, "set_rewards_config", (config.clone(),).into_val(&env))?;
+
+        if !config.is_valid() {
+            return Err(Error::InvalidConfig);
+        }
+
+        storage::set_rewards_config(&env, &config);
+
+        events::emit_rewards_config_updated(&env, &config);
+
+        Ok(())
+    }
+
+    /// Get the current reward formula
+    pub fn get_rewards_config(env: Env) -> types::RewardsConfig {
+        storage::get_rewards_config(&env)
+    }
+
+    /// Set how much a token's volume counts towards rewards
+    ///
+    /// Admin only. Volume is measured in the default token, which always
+    /// counts one for one, so its rate can't be changed. Other tokens count
+    /// for nothing until their rate is set. Volume already recorded keeps
+    /// the rate it was recorded at.
+    pub fn set_volume_rate(env: Env, token: Address, rate: VolumeRate) -> Result<(), Error> {
+        Self::require_admin(&env, "set_volume_rate", (token.clone(), rate.clone()).into_val(&env))?;
+
+        if !rate.is_valid() || token == storage::get_token(&env) {
+            return Err(Error::InvalidConfig);
+        }
+
+        storage::set_volume_rate(&env, &token, &rate);
+
+        events::emit_volume_rate_updated(&env, &token, &rate);
+
+        Ok(())
+    }
+
+    /// Get how much a token's volume counts towards rewards
+    pub fn get_volume_rate(env: Env, token: Address) -> VolumeRate {
+        Self::volume_rate(&env, &token)
+    }
+
+    /// Claim rewards for a user
+    ///
+    /// This function allows users to claim their earned rewards. Only the
+    /// user can claim, and the payout always goes to them.
+    pub fn claim_rewards(
+        env: Env,
+        user: Address,
+    ) -> Result<i128, Error> {
+        user.require_auth();
+
+        Self::require_not_paused(&env, PauseScope::Rewards)?;
+
+        // Get user rewards data
+        let mut rewards = storage::get_user_rewards(&env, &user)
+            .ok_or(Error::UserNotFound)?;
+
+        // Check if user is eligible for rewards
+        if rewards.status != types::RewardsStatus::Active {
+            return Err(Error::RewardsAlreadyClaimed);
+        }
+
+        // Calculate available rewards
+        let available_rewards = rewards.rewards_earned - rewards.rewards_claimed;
+        
+        if available_rewards <= 0 {
+            return Err(Error::InsufficientRewards);
+        }
+
+        let treasury = storage::get_rewards_treasury(&env);
+        if treasury < available_rewards {
+            return Err(Error::InsufficientRewardsTreasury);
+        }
+
+        // Update claimed rewards
+        rewards.rewards_claimed += available_rewards;
+        rewards.last_activity = env.ledger().timestamp();
+        
+        // Store updated rewards
+        storage::set_user_rewards(&env, &user, &rewards);
+        storage::set_rewards_treasury(&env, treasury - available_rewards);
+        storage::adjust_rewards_liabilities(&env, -available_rewards);
+
+        // Pay out of the treasury
+        let token_address = storage::get_rewards_token(&env);
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &user, &available_rewards);
+
+        // Emit rewards claimed event
+        events::emit_rewards_claimed(&env, &user, available_rewards);
+
+        Ok(available_rewards)
+    }
+
+    /// Get a user's rewards record
+    pub fn get_user_rewards_info(env: Env, user: Address) -> Result<types::UserRewards, Error> {
+        storage::get_user_rewards(&env, &user).ok_or(Error::UserNotFound)
+    }
+
+    /// Set the token rewards are paid in
+    ///
+    /// Admin only. I'm refusing the switch while the treasury still holds
+    /// the old token, since its balance would otherwise be misreported.
+    pub fn set_rewards_token(env: Env, token: Address) -> Result<(), Error> {
+        Self::require_admin(&env, "set_rewards_token", (token.clone(),).into_val(&env))?;
+
+        // Balances already in the treasury are in the old token
+        if storage::get_rewards_treasury(&env) != 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        storage::set_rewards_token(&env, &token);
+
+        events::emit_rewards_token_set(&env, &token);
+
+        Ok(())
+    }
+
+    /// Fund the rewards treasury
+    ///
+    /// Admin only. Transfers `amount` of the rewards token into the contract
+    /// and returns the new treasury balance.
+    pub fn fund_rewards(env: Env, amount: i128) -> Result<i128, Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token_address = storage::get_rewards_token(&env);
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&admin, &env.current_contract_address(), &amount);
+
+        let balance = storage::get_rewards_treasury(&env) + amount;
+        storage::set_rewards_treasury(&env, balance);
+
+        events::emit_rewards_funded(&env, &admin, amount, balance);
+
+        Ok(balance)
+    }
+
+    /// Get the rewards treasury balance alongside unclaimed liabilities
+    pub fn get_rewards_treasury(env: Env) -> types::RewardsTreasury {
+        types::RewardsTreasury {
+            token: storage::get_rewards_token(&env),
+            balance: storage::get_rewards_treasury(&env),
+            unclaimed_liabilities: storage::get_rewards_liabilities(&env),
+        }
+    }
+
+    /// Submit verification for a split
+    ///
+    /// This function allows users to submit verification requests with evidence.
+    pub fn submit_verification(
+        env: Env,
+        requester: Address,
+        split_id: String,
+        receipt_hash: String,
+    ) -> Result<String, Error> {
+        requester.require_auth();
+
+        Self::require_not_paused(&env, PauseScope::All)?;
+
+        // Check if split exists
+        let split_id_num = format::parse_u64(&split_id).ok_or(Error::SplitNotFound)?;
+
+        if !storage::has_split(&env, split_id_num) {
+            return Err(Error::SplitNotFound);
+        }
+
+        // Requests are filed under the canonical ID, so "07" can't get
+        // around the one-pending-request rule for split 7
+        let split_id = format::u64_to_string(&env, split_id_num);
+
+        // Only one request per split can be waiting on the oracles, so only
+        // the newest one can still be pending
+        if let Some(latest_id) = storage::get_split_verifications(&env, &split_id).last() {
+            if let Some(existing) = storage::get_verification_request(&env, &latest_id) {
+                if existing.status == types::VerificationStatus::Pending {
+                    return Err(Error::VerificationAlreadyExists);
+                }
+            }
+        }
+
+        // Generate verification ID
+        let verification_id = storage::get_next_verification_id(&env);
+
+        // Create verification request
+        let request = types::VerificationRequest {
+            verification_id: verification_id.clone(),
+            split_id: split_id.clone(),
+            requester: requester.clone(),
+            receipt_hash: receipt_hash.clone(),
+            evidence_url: None,
+            submitted_at: env.ledger().timestamp(),
+            status: types::VerificationStatus::Pending,
             verified_by: None,
             verified_at: None,
             rejection_reason: None,
+            approvals: 0,
+            rejections: 0,
+        };
+
+        // Store verification request
+        storage::set_verification_request(&env, &verification_id, &request);
+        storage::add_split_verification(&env, &split_id, &verification_id);
+
+        // Emit verification submitted event
+        events::emit_verification_submitted(&env, &verification_id, &split_id, &requester);
+
+        Ok(verification_id)
+    }
+
+    /// Verify a split
+    ///
+    /// Each authorized oracle gets one vote. I'm only deciding the request
+    /// once `required_verifications` votes agree, so a single compromised
+    /// oracle can't push a split through on its own.
+    pub fn verify_split(
+        env: Env,
+        oracle: Address,
+        verification_id: String,
+        verified: bool,
+    ) -> Result<(), Error> {
+        oracle.require_auth();
+
+        Self::require_not_paused(&env, PauseScope::All)?;
+
+        // Get verification request
+        let mut request = storage::get_verification_request(&env, &verification_id)
+            .ok_or(Error::VerificationNotFound)?;
+
+        // Check the oracle is one we trust
+        let oracle_config = storage::get_oracle_config(&env)
+            .ok_or(Error::OracleNotAuthorized)?;
+
+        if oracle_config.oracle_addresses.len() < oracle_config.min_oracles {
+            return Err(Error::InsufficientOracles);
+        }
+        
+        if !oracle_config.oracle_addresses.contains(&oracle) {
+            return Err(Error::OracleNotAuthorized);
+        }
+
+        // Check if verification is still pending
+        if request.status != types::VerificationStatus::Pending {
+            return Err(Error::InvalidVerificationStatus);
+        }
+
+        if Self::is_verification_timed_out(&env, &request, &oracle_config) {
+            return Err(Error::VerificationExpired);
+        }
+
+        if storage::get_attestation(&env, &verification_id, &oracle).is_some() {
+            return Err(Error::AlreadyAttested);
+        }
+
+        // Record the vote
+        storage::set_attestation(&env, &verification_id, &oracle, verified);
+        if verified {
+            request.approvals += 1;
+        } else {
+            request.rejections += 1;
+        }
+        events::emit_oracle_attested(&env, &verification_id, &oracle, verified);
+
+        // Decide the request once enough oracles agree
+        let quorum = core::cmp::max(oracle_config.required_verifications, 1);
+        let decided = if request.approvals >= quorum {
+            request.status = types::VerificationStatus::Verified;
+            true
+        } else if request.rejections >= quorum {
+            request.status = types::VerificationStatus::Rejected;
+            request.rejection_reason = Some(String::from_str(&env, "Evidence insufficient"));
+            true
+        } else {
+            false
         };
 
-        // Store verification request
+        if decided {
+            request.verified_by = Some(oracle.clone());
+            request.verified_at = Some(env.ledger().timestamp());
+            storage::set_latest_verification(&env, &request.split_id, &verification_id);
+        }
+
+        // Store updated request
+        storage::set_verification_request(&env, &verification_id, &request);
+
+        if decided {
+            events::emit_verification_completed(&env, &verification_id, verified, &oracle);
+        }
+
+        if request.status == types::VerificationStatus::Rejected {
+            Self::refund_rejected_split(&env, &request.split_id);
+        }
+
+        Ok(())
+    }
+
+    /// Expire a verification request the oracles didn't decide in time
+    ///
+    /// Permissionless, like `expire_split`. Once expired the requester can
+    /// submit a fresh request for the split.
+    pub fn expire_verification(env: Env, verification_id: String) -> Result<(), Error> {
+        let mut request = storage::get_verification_request(&env, &verification_id)
+            .ok_or(Error::VerificationNotFound)?;
+
+        if request.status != types::VerificationStatus::Pending {
+            return Err(Error::InvalidVerificationStatus);
+        }
+
+        let oracle_config = storage::get_oracle_config(&env)
+            .ok_or(Error::OracleNotAuthorized)?;
+
+        if !Self::is_verification_timed_out(&env, &request, &oracle_config) {
+            return Err(Error::VerificationNotExpired);
+        }
+
+        request.status = types::VerificationStatus::Expired;
+        request.verified_at = Some(env.ledger().timestamp());
         storage::set_verification_request(&env, &verification_id, &request);
+        storage::set_latest_verification(&env, &request.split_id, &verification_id);
 
-        // Emit verification submitted event
-        events::emit_verification_submitted(&env, &verification_id, &split_id, &caller);
+        events::emit_verification_status_changed(&env, &verification_id, "pending", "expired");
 
-        Ok(verification_id)
+        Ok(())
     }
 
-    /// Verify a split
+    /// Register an oracle
     ///
-    /// This function allows authorized oracles to verify split legitimacy.
-    pub fn verify_split(
-        env: Env,
-        verification_id: String,
-        verified: bool,
-    ) -> Result<(), Error> {
-        // Get caller's address (require_auth for the caller)
-        let caller = env.current_contract_address();
-        caller.require_auth();
+    /// Admin only. The first oracle creates a single-oracle config; after
+    /// that the existing thresholds are kept.
+    pub fn add_oracle(env: Env, oracle: Address) -> Result<(), Error> {
+        Self::require_admin(&env, "add_oracle", (oracle.clone(),).into_val(&env))?;
+
+        let mut config = storage::get_oracle_config(&env).unwrap_or(types::OracleConfig::new(&env));
+        if config.oracle_addresses.contains(&oracle) {
+            return Err(Error::OracleAlreadyExists);
+        }
 
-        // Get verification request
-        let mut request = storage::get_verification_request(&env, &verification_id)
-            .ok_or(Error::VerificationNotFound)?;
+        config.oracle_addresses.push_back(oracle.clone());
+        storage::set_oracle_config(&env, &config);
 
-        // Check if caller is authorized oracle
-        let oracle_config = storage::get_oracle_config(&env)
-            .ok_or(Error::OracleNotAuthorized)?;
-        
-        if !oracle_config.oracle_addresses.contains(&caller) {
-            return Err(Error::OracleNotAuthorized);
+        events::emit_oracle_added(&env, &oracle);
+
+        Ok(())
+    }
+
+    /// Remove an oracle
+    ///
+    /// Admin only. Refused if the remaining oracles couldn't reach
+    /// `required_verifications`; lower the threshold first.
+    pub fn remove_oracle(env: Env, oracle: Address) -> Result<(), Error> {
+        Self::require_admin(&env, "remove_oracle", (oracle.clone(),).into_val(&env))?;
+
+        let mut config = storage::get_oracle_config(&env).ok_or(Error::OracleNotFound)?;
+        let index = config
+            .oracle_addresses
+            .first_index_of(&oracle)
+            .ok_or(Error::OracleNotFound)?;
+
+        config.oracle_addresses.remove(index);
+        if !config.is_valid() {
+            return Err(Error::InvalidConfig);
         }
 
-        // Check if verification is still pending
-        if request.status != types::VerificationStatus::Pending {
-            return Err(Error::InvalidVerificationStatus);
+        storage::set_oracle_config(&env, &config);
+
+        events::emit_oracle_removed(&env, &oracle);
+
+        Ok(())
+    }
+
+    /// Replace the oracle set and thresholds in one go
+    ///
+    /// Admin only. `required_verifications` must be between 1 and the
+    /// number of oracles, and each oracle may only be listed once.
+    pub fn set_oracle_config(env: Env, config: types::OracleConfig) -> Result<(), Error> {
+        Self::require_admin(&env, "set_oracle_config", (config.clone(),).into_val(&env))?;
+
+        if !config.is_valid() {
+            return Err(Error::InvalidConfig);
         }
 
-        // Update verification request
-        request.status = if verified {
-            types::VerificationStatus::Verified
-        } else {
-            types::VerificationStatus::Rejected
-        };
-        request.verified_by = Some(caller);
-        request.verified_at = Some(env.ledger().timestamp());
+        storage::set_oracle_config(&env, &config);
 
-        if !verified {
-            request.rejection_reason = Some(String::from_str(&env, "Evidence insufficient"));
+        events::emit_oracle_config_updated(&env, &config);
+
+        Ok(())
+    }
+
+    /// Get the oracle config, if one has been set
+    pub fn get_oracle_config(env: Env) -> Option<types::OracleConfig> {
+        storage::get_oracle_config(&env)
+    }
+
+    /// Set the global verification policy
+    ///
+    /// Admin only. Splits above `amount_threshold` can't be released until
+    /// verified, and a rejection sends them to refunds instead.
+    pub fn set_verification_policy(env: Env, policy: types::VerificationPolicy) -> Result<(), Error> {
+        Self::require_admin(&env, "set_verification_policy", (policy.clone(),).into_val(&env))?;
+
+        if policy.amount_threshold < 0 {
+            return Err(Error::InvalidAmount);
         }
 
-        // Store updated request
-        storage::set_verification_request(&env, &verification_id, &request);
+        storage::set_verification_policy(&env, &policy);
+
+        events::emit_verification_policy_updated(&env, &policy);
+
+        Ok(())
+    }
+
+    /// Get the global verification policy
+    pub fn get_verification_policy(env: Env) -> types::VerificationPolicy {
+        storage::get_verification_policy(&env)
+    }
+
+    /// Hold a split's funds until it's verified, whatever its size
+    ///
+    /// Creator only, and there's no opting back out.
+    pub fn require_split_verification(env: Env, split_id: u64) -> Result<(), Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let split = storage::get_split(&env, split_id);
+        split.creator.require_auth();
+
+        match split.status {
+            SplitStatus::Released => return Err(Error::SplitReleased),
+            SplitStatus::Cancelled => return Err(Error::SplitCancelled),
+            SplitStatus::Expired => return Err(Error::SplitExpired),
+            _ => {}
+        }
+
+        storage::set_split_requires_verification(&env, split_id);
 
-        // Emit verification completed event
-        events::emit_verification_completed(&env, &verification_id, verified, &caller);
+        events::emit_split_verification_required(&env, split_id);
 
         Ok(())
     }
 
+    /// Check whether a split's funds are held until it's verified
+    pub fn is_verification_required(env: Env, split_id: u64) -> Result<bool, Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let split = storage::get_split(&env, split_id);
+        Ok(Self::needs_verification(&env, &split))
+    }
+
+    /// Get a verification request by ID
+    pub fn get_verification_request(
+        env: Env,
+        verification_id: String,
+    ) -> Result<types::VerificationRequest, Error> {
+        storage::get_verification_request(&env, &verification_id).ok_or(Error::VerificationNotFound)
+    }
+
     /// Get verification status for a split
     ///
-    /// This function returns the current verification status of a split.
+    /// This function returns the status of the split's most recently
+    /// decided request. I'm reading it through a stored pointer, so it costs
+    /// the same no matter how many verifications exist.
     pub fn get_verification_status(
         env: Env,
         split_id: String,
     ) -> types::VerificationStatus {
-        // Get all verification requests for this split
-        let verification_ids = storage::get_split_verifications(&env, &split_id);
-
-        // Find the most recent verification
-        let mut latest_status = types::VerificationStatus::Pending;
-        let mut latest_timestamp = 0u64;
-
-        for verification_id in verification_ids.iter() {
-            if let Some(request) = storage::get_verification_request(&env, verification_id) {
-                match request.status {
-                    types::VerificationStatus::Verified => {
-                        if request.verified_at.unwrap_or(0) > latest_timestamp {
-                            latest_timestamp = request.verified_at.unwrap();
-                            latest_status = types::VerificationStatus::Verified;
-                        }
-                    },
-                    types::VerificationStatus::Rejected => {
-                        if request.verified_at.unwrap_or(0) > latest_timestamp {
-                            latest_timestamp = request.verified_at.unwrap();
-                            latest_status = types::VerificationStatus::Rejected;
-                        }
-                    },
-                    _ => {}
+        // Splits with no decided request yet are still pending
+        let split_id = Self::canonical_split_id(&env, &split_id);
+        storage::get_latest_verification(&env, &split_id)
+            .and_then(|verification_id| storage::get_verification_request(&env, &verification_id))
+            .map(|request| request.status)
+            .unwrap_or(types::VerificationStatus::Pending)
+    }
+
+    /// Get every verification request submitted for a split, oldest first
+    pub fn get_split_verifications(env: Env, split_id: String) -> Vec<String> {
+        storage::get_split_verifications(&env, &Self::canonical_split_id(&env, &split_id))
+    }
+}
+
+impl SplitEscrowContract {
+    /// Check whether a split has collected its full total
+    fn is_fully_funded_internal(split: &Split) -> bool {
+        split.amount_collected >= split.total_amount
+    }
+
+    /// Load a split whose participants the creator may still change
+    ///
+    /// Requires the creator's auth, and the split must still be collecting
+    /// deposits.
+    fn get_editable_split(env: &Env, split_id: u64) -> Result<Split, Error> {
+        if !storage::has_split(env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+        let split = storage::get_split(env, split_id);
+
+        split.creator.require_auth();
+
+        if split.status == SplitStatus::Expired || Self::is_past_deadline(env, &split) {
+            return Err(Error::SplitExpired);
+        }
+
+        if split.status != SplitStatus::Pending && split.status != SplitStatus::Active {
+            return Err(Error::NotAcceptingDeposits);
+        }
+
+        Ok(split)
+    }
+
+    /// Get the token a policy is paid in: that of the split it insures
+    fn policy_token(env: &Env, policy: &types::InsurancePolicy) -> Address {
+        // Policies are keyed on the canonical split ID, which always parses
+        let split_id = format::parse_u64(&policy.split_id).unwrap();
+        storage::get_split(env, split_id).token
+    }
+
+    /// Find a participant's index in a split
+    fn find_participant(split: &Split, participant: &Address) -> Option<u32> {
+        (0..split.participants.len()).find(|&i| split.participants.get(i).unwrap().address == *participant)
+    }
+
+    /// Release a split whose participant changes left it fully funded
+    ///
+    /// As with the auto-release in `deposit`, a release held by a pause or
+    /// verification leaves the split Active for a later `release_funds`.
+    fn release_if_funded(env: &Env, split_id: u64, split: Split) {
+        if split.amount_collected > 0 && Self::is_fully_funded_internal(&split) {
+            let _ = Self::release_funds_internal(env, split_id, split);
+        }
+    }
+
+    /// Send everything the contract holds for a funded split to its recipients
+    ///
+    /// The split is saved as Released and the amount sent is returned.
+    fn release_funds_internal(env: &Env, split_id: u64, mut split: Split) -> Result<i128, Error> {
+        match split.status {
+            SplitStatus::Cancelled => return Err(Error::SplitCancelled),
+            SplitStatus::Released => return Err(Error::SplitReleased),
+            SplitStatus::Expired => return Err(Error::SplitExpired),
+            _ => {}
+        }
+
+        if !Self::is_fully_funded_internal(&split) {
+            return Err(Error::SplitNotFunded);
+        }
+
+        Self::require_not_paused(env, PauseScope::Releases)?;
+
+        // Held splits stay funded until the oracles sign off
+        if !Self::is_verification_satisfied(env, &split) {
+            return Err(Error::VerificationRequired);
+        }
+
+        // release_partial may already have sent some of it
+        let amount = split.amount_collected - split.amount_released;
+        Self::pay_release(env, &split, amount)?;
+
+        split.amount_released += amount;
+        split.status = SplitStatus::Released;
+        storage::set_split(env, split_id, &split);
+
+        events::emit_escrow_completed(env, split_id, split.total_amount);
+
+        // Rewards wait for the split to be paid for, so deposits that are
+        // later refunded, and empty or abandoned splits, earn nothing
+        for p in split.participants.iter() {
+            if p.amount_paid > 0 {
+                Self::record_activity(env, &p.address, ActivityType::SplitParticipated, split_id, &split.token, 0);
+                Self::record_activity(env, &p.address, ActivityType::DepositMade, split_id, &split.token, p.amount_paid);
+            }
+        }
+        Self::record_activity(env, &split.creator, ActivityType::SplitCreated, split_id, &split.token, split.total_amount);
+        Self::record_activity(env, &split.creator, ActivityType::SplitCompleted, split_id, &split.token, amount);
+
+        Ok(amount)
+    }
+
+    /// Get the fee bounds for a token
+    ///
+    /// Bounds set with `set_fee_bounds` win; otherwise the config's own apply
+    /// to the default token, and other tokens are only charged `fee_bps`.
+    fn fee_bounds(env: &Env, config: &FeeConfig, token: &Address) -> FeeBounds {
+        match storage::get_fee_bounds(env, token) {
+            Some(bounds) => bounds,
+            None if *token == storage::get_token(env) => config.bounds(),
+            None => FeeBounds::none(),
+        }
+    }
+
+    /// Pay released funds to the recipients, keeping back the platform fee
+    ///
+    /// `split` is the state before this release. The fee is worked out on
+    /// everything released from the split so far, less what earlier
+    /// releases already paid, so releasing in parts costs the same as
+    /// releasing in one go. The payout and the fee are each shared by
+    /// recipient weight with `allocation::largest_remainder`, and every
+    /// recipient gets its own `released` event. Returns the fee kept back.
+    fn pay_release(env: &Env, split: &Split, amount: i128) -> Result<i128, Error> {
+        if amount <= 0 {
+            return Ok(0);
+        }
+
+        let fee = match storage::get_fee_config(env) {
+            Some(config) if !storage::is_fee_exempt(env, &split.creator) => {
+                let bounds = Self::fee_bounds(env, &config, &split.token);
+                let already_paid = storage::get_split_fees(env, split.id);
+                let due = config.fee_for(split.amount_released + amount, &bounds) - already_paid;
+                due.clamp(0, amount)
+            }
+            _ => 0,
+        };
+
+        let recipients = Self::split_recipients(env, split);
+        let mut weights = Vec::new(env);
+        for recipient in recipients.iter() {
+            weights.push_back(recipient.weight as i128);
+        }
+        let payouts = allocation::largest_remainder(env, amount - fee, &weights)?;
+        let fees = allocation::largest_remainder(env, fee, &weights)?;
+
+        let token_client = token::Client::new(env, &split.token);
+        for i in 0..recipients.len() {
+            let recipient = recipients.get(i).unwrap().address;
+            let payout = payouts.get(i).unwrap();
+            if payout > 0 {
+                token_client.transfer(&env.current_contract_address(), &recipient, &payout);
+            }
+
+            events::emit_funds_released(
+                env,
+                split.id,
+                &recipient,
+                payout,
+                fees.get(i).unwrap(),
+                env.ledger().timestamp(),
+            );
+        }
+
+        if fee > 0 {
+            let balance = storage::get_fee_balance(env, &split.token);
+            storage::set_fee_balance(env, &split.token, balance + fee);
+            let paid = storage::get_split_fees(env, split.id);
+            storage::set_split_fees(env, split.id, paid + fee);
+        }
+
+        Ok(fee)
+    }
+
+    /// Get who a split pays out to, defaulting to just its creator
+    fn split_recipients(env: &Env, split: &Split) -> Vec<Recipient> {
+        storage::get_split_recipients(env, split.id).unwrap_or_else(|| {
+            Vec::from_array(
+                env,
+                [Recipient {
+                    address: split.creator.clone(),
+                    weight: 1,
+                }],
+            )
+        })
+    }
+
+    /// Check a recipient list can be paid out
+    fn validate_recipients(recipients: &Vec<Recipient>) -> Result<(), Error> {
+        if recipients.is_empty() {
+            return Err(Error::NoParticipants);
+        }
+
+        if recipients.len() > MAX_RECIPIENTS {
+            return Err(Error::InvalidAmount);
+        }
+
+        for i in 0..recipients.len() {
+            let recipient = recipients.get(i).unwrap();
+            if recipient.weight == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            for j in (i + 1)..recipients.len() {
+                if recipients.get(j).unwrap().address == recipient.address {
+                    return Err(Error::InvalidEscrow);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the rate a token's volume counts towards rewards at
+    ///
+    /// The default token counts one for one. Other tokens count for nothing
+    /// until the admin sets their rate, since their units can't be compared.
+    fn volume_rate(env: &Env, token: &Address) -> VolumeRate {
+        if *token == storage::get_token(env) {
+            return VolumeRate { numerator: 1, denominator: 1 };
+        }
+        storage::get_volume_rate(env, token).unwrap_or(VolumeRate { numerator: 0, denominator: 1 })
+    }
+
+    /// Record a rewards-earning activity and roll it into the user's totals
+    ///
+    /// Creations and participations count towards the per-split rewards;
+    /// deposits and releases count towards volume. `amount` is in `token`,
+    /// and is converted into default-token units before it's added to the
+    /// volume, so amounts in different tokens aren't summed as if equal.
+    fn record_activity(
+        env: &Env,
+        user: &Address,
+        activity_type: ActivityType,
+        split_id: u64,
+        token: &Address,
+        amount: i128,
+    ) {
+        let now = env.ledger().timestamp();
+        let mut rewards = storage::get_user_rewards(env, user).unwrap_or(UserRewards {
+            user: user.clone(),
+            total_splits_created: 0,
+            total_splits_participated: 0,
+            total_amount_transacted: 0,
+            rewards_earned: 0,
+            rewards_claimed: 0,
+            last_activity: now,
+            status: RewardsStatus::Active,
+        });
+
+        match activity_type {
+            ActivityType::SplitCreated => rewards.total_splits_created += 1,
+            ActivityType::SplitParticipated => rewards.total_splits_participated += 1,
+            ActivityType::DepositMade | ActivityType::SplitCompleted => {
+                rewards.total_amount_transacted += Self::volume_rate(env, token).convert(amount)
+            }
+        }
+        rewards.last_activity = now;
+        storage::set_user_rewards(env, user, &rewards);
+
+        let activity = UserActivity {
+            user: user.clone(),
+            activity_type,
+            split_id,
+            amount,
+            timestamp: now,
+        };
+        let activity_id = storage::get_next_activity_id(env);
+        storage::set_user_activity(env, user, activity_id, &activity);
+
+        events::emit_activity_tracked(env, user, activity_type, split_id, amount);
+    }
+
+    /// Check the admin signed the call, plus the council if one is set
+    ///
+    /// Council approvals are tied to this exact action and arguments, and
+    /// are used up once the action goes through.
+    fn require_admin(env: &Env, action: &str, args: Vec<Val>) -> Result<(), Error> {
+        let admin = storage::get_admin(env);
+        admin.require_auth();
+
+        if let Some(council) = storage::get_admin_council(env) {
+            let action_hash = Self::admin_action_hash(env.clone(), Symbol::new(env, action), args);
+
+            // Signers removed from the council since approving don't count
+            let mut approved = 0;
+            for signer in storage::get_admin_approvals(env, &action_hash).iter() {
+                if council.signers.contains(&signer) {
+                    approved += 1;
                 }
             }
+            if approved < council.threshold {
+                return Err(Error::Unauthorized);
+            }
+
+            storage::remove_admin_approvals(env, &action_hash);
+        }
+
+        Ok(())
+    }
+
+    /// Reject the call if the admin has paused its scope or the whole contract
+    fn require_not_paused(env: &Env, scope: PauseScope) -> Result<(), Error> {
+        if storage::is_paused(env, PauseScope::All) || storage::is_paused(env, scope) {
+            return Err(Error::ContractPaused);
+        }
+
+        Ok(())
+    }
+
+    /// Check whether the verification policy or the creator holds this split
+    fn needs_verification(env: &Env, split: &Split) -> bool {
+        let policy = storage::get_verification_policy(env);
+        (policy.enabled && split.total_amount > policy.amount_threshold)
+            || storage::split_requires_verification(env, split.id)
+    }
+
+    /// Check whether a split may release funds as far as verification goes
+    fn is_verification_satisfied(env: &Env, split: &Split) -> bool {
+        if !Self::needs_verification(env, split) {
+            return true;
+        }
+
+        let split_id = format::u64_to_string(env, split.id);
+        Self::get_verification_status(env.clone(), split_id) == types::VerificationStatus::Verified
+    }
+
+    /// Write a numeric split ID in canonical form, so "007" becomes "7"
+    ///
+    /// IDs that aren't numbers are returned as they are.
+    fn canonical_split_id(env: &Env, split_id: &String) -> String {
+        match format::parse_u64(split_id) {
+            Some(id) => format::u64_to_string(env, id),
+            None => split_id.clone(),
+        }
+    }
+
+    /// Cancel a held split whose verification was rejected and refund it
+    ///
+    /// Only a request filed under the split's canonical ID counts, since
+    /// that's the one release gating reads.
+    fn refund_rejected_split(env: &Env, split_id: &String) {
+        let split_id_num = match format::parse_u64(split_id) {
+            Some(id) if storage::has_split(env, id) => id,
+            _ => return,
+        };
+        if *split_id != format::u64_to_string(env, split_id_num) {
+            return;
+        }
+        let split_id = split_id_num;
+
+        let mut split = storage::get_split(env, split_id);
+        if !Self::needs_verification(env, &split) {
+            return;
+        }
+
+        if split.status != SplitStatus::Pending && split.status != SplitStatus::Active {
+            return;
+        }
+
+        split.status = SplitStatus::Cancelled;
+        events::emit_split_cancelled(env, split_id);
+
+        Self::open_refunds(env, &mut split);
+
+        storage::set_split(env, split_id, &split);
+    }
+
+    /// Check whether a pending verification request has run out of time
+    fn is_verification_timed_out(env: &Env, request: &types::VerificationRequest, config: &types::OracleConfig) -> bool {
+        env.ledger().timestamp() > request.submitted_at.saturating_add(config.verification_timeout)
+    }
+
+    /// Check whether a split's deadline has passed
+    fn is_past_deadline(env: &Env, split: &Split) -> bool {
+        match split.deadline {
+            Some(deadline) => env.ledger().timestamp() > deadline,
+            None => false,
+        }
+    }
+
+    /// Refund everyone in the same call when the split is small enough
+    ///
+    /// Larger splits are left for participants to pull with `claim_refund`.
+    fn open_refunds(env: &Env, split: &mut Split) {
+        if split.participants.len() > MAX_CANCEL_REFUNDS {
+            return;
+        }
+
+        let allocations = Self::refund_allocations(env, split);
+        for i in 0..split.participants.len() {
+            Self::refund_participant_internal(env, split, i, allocations.get(i).unwrap());
+        }
+    }
+
+    /// Refund escrow payments in the same call when the escrow is small enough
+    fn open_escrow_refunds(env: &Env, escrow: &mut SplitEscrow) {
+        if escrow.participants.len() > MAX_CANCEL_REFUNDS {
+            return;
+        }
+
+        for i in 0..escrow.participants.len() {
+            Self::refund_escrow_participant(env, escrow, i);
+        }
+    }
+
+    /// Return an escrow participant's payments
+    ///
+    /// Escrow funds are never partially released, so I'm simply unwinding
+    /// the payment. Zeroing `amount_paid` and `amount_collected` together
+    /// keeps `SplitEscrow::validate` happy afterwards.
+    fn refund_escrow_participant(env: &Env, escrow: &mut SplitEscrow, index: u32) -> i128 {
+        let mut p = escrow.participants.get(index).unwrap();
+        let owed = p.amount_paid;
+        if owed <= 0 {
+            return 0;
+        }
+
+        let token_address = storage::get_token(env);
+        let token_client = token::Client::new(env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &p.address, &owed);
+
+        p.amount_paid = 0;
+        p.paid_at = None;
+        escrow.participants.set(index, p.clone());
+        escrow.amount_collected -= owed;
+        storage::set_participant_payment(env, &escrow.split_id, &p.address, 0);
+
+        events::emit_escrow_refund(env, &escrow.split_id, &p.address, owed);
+
+        owed
+    }
+
+    /// Work out how much each participant gets back from a closed split
+    ///
+    /// Anything `release_partial` already paid out is gone, so I'm
+    /// sharing out what the contract still holds pro rata to each deposit.
+    /// Rounding dust goes to the first participant who paid anything, which
+    /// keeps the result deterministic and returns the full balance.
+    fn refund_allocations(env: &Env, split: &Split) -> Vec<i128> {
+        let held = split.amount_collected - split.amount_released;
+        let mut allocations = Vec::new(env);
+        let mut allocated: i128 = 0;
+        let mut first_payer: Option<u32> = None;
+
+        for i in 0..split.participants.len() {
+            let p = split.participants.get(i).unwrap();
+            let amount = if split.amount_collected > 0 {
+                p.amount_paid * held / split.amount_collected
+            } else {
+                0
+            };
+
+            if p.amount_paid > 0 && first_payer.is_none() {
+                first_payer = Some(i);
+            }
+
+            allocated += amount;
+            allocations.push_back(amount);
+        }
+
+        if let Some(i) = first_payer {
+            let dust = held - allocated;
+            allocations.set(i, allocations.get(i).unwrap() + dust);
         }
 
-        latest_status
+        allocations
+    }
+
+    /// Send a participant whatever is still owed from their allocation
+    ///
+    /// The caller is responsible for persisting the updated split.
+    fn refund_participant_internal(env: &Env, split: &mut Split, index: u32, allocation: i128) -> i128 {
+        let mut p = split.participants.get(index).unwrap();
+        let owed = allocation - p.amount_refunded;
+        if owed <= 0 {
+            return 0;
+        }
+
+        let token_client = token::Client::new(env, &split.token);
+        token_client.transfer(&env.current_contract_address(), &p.address, &owed);
+
+        p.amount_refunded += owed;
+        split.participants.set(index, p.clone());
+
+        events::emit_refund_processed(env, split.id, &p.address, owed);
+
+        owed
     }
 }
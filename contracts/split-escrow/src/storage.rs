@@ -6,38 +6,16 @@
 //! This module includes both original storage patterns and the enhanced
 //! escrow storage keys as specified in issue #59.
 
-use soroban_sdk::{contracttype, Address, Env, String, symbol_short, Vec, Symbol};
-use crate::types::{Split, SplitEscrow};
-
-
-const ADMIN: Symbol = symbol_short!("ADMIN");
-const INIT: Symbol = symbol_short!("INIT");
-
-pub fn is_initialized(env: &Env) -> bool {
-    env.storage().instance().has(&INIT)
-}
-
-pub fn set_initialized(env: &Env) {
-    env.storage().instance().set(&INIT, &true);
-}
-
-pub fn set_admin(env: &Env, admin: &Address) {
-    env.storage().instance().set(&ADMIN, admin);
-}
-
-pub fn get_admin(env: &Env) -> Address {
-    env.storage().instance().get(&ADMIN).unwrap()
-}
-
-pub fn save_escrow(env: &Env, escrow: &SplitEscrow) {
-    env.storage()
-        .persistent()
-        .set(&escrow.split_id, escrow);
-}
-
-pub fn get_escrow(env: &Env, split_id: &String) -> Option<SplitEscrow> {
-    env.storage().persistent().get(split_id)
-}
+use soroban_sdk::{
+    contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, String, Symbol, TryFromVal, Val,
+    Vec,
+};
+use crate::format;
+use crate::types::{
+    AdminCouncil, AdminProposal, FeeBounds, FeeConfig, OracleConfig, PauseScope, Recipient,
+    RewardsCheckpoint, RewardsConfig, Split, SplitEscrow, SplitV0, SplitV1, UserActivity,
+    UserRewards, VerificationPolicy, VerificationRequest, VerificationRequestV0, VolumeRate,
+};
 
 // ============================================
 // Original Storage Keys
@@ -61,6 +39,30 @@ pub enum DataKey {
 
     /// Whether the contract is initialized
     Initialized,
+
+    /// Whether the admin has paused a part of the contract
+    Paused(PauseScope),
+
+    /// An admin handover waiting to be accepted
+    PendingAdmin,
+
+    /// Signers who must approve config changes
+    AdminCouncil,
+
+    /// Council approvals for an admin action, indexed by the action's hash
+    AdminApprovals(BytesN<32>),
+
+    /// Layout version of the stored records
+    SchemaVersion,
+
+    /// Next split ID an unfinished migration will look at
+    MigrationCursor,
+
+    /// Tokens besides the default one that splits may be created in
+    AllowedTokens,
+
+    /// Who a split pays out to, when that isn't just its creator
+    SplitRecipients(u64),
 }
 
 // ============================================
@@ -107,6 +109,20 @@ pub enum StorageKey {
 
     /// Maps insurance_id to claim_id (one-to-many)
     InsuranceClaims(String),
+
+    /// Insurance pool reserves and exposure for the default token
+    InsurancePool,
+
+    /// Capital contributed by a liquidity provider in the default token
+    PoolProvider(Address),
+
+    /// Insurance pool for any other token
+    /// Maps: token -> InsurancePool
+    TokenInsurancePool(Address),
+
+    /// Capital contributed by a liquidity provider in any other token
+    /// Maps: (provider, token) -> i128 amount
+    TokenPoolProvider(Address, Address),
 }
 
 /// Time-to-live for persistent storage (about 1 year)
@@ -142,6 +158,88 @@ pub fn set_admin(env: &Env, admin: &Address) {
     );
 }
 
+/// Get the admin handover waiting to be accepted, if any
+pub fn get_pending_admin(env: &Env) -> Option<AdminProposal> {
+    env.storage().persistent().get(&DataKey::PendingAdmin)
+}
+
+/// Store an admin handover proposal
+pub fn set_pending_admin(env: &Env, proposal: &AdminProposal) {
+    env.storage().persistent().set(&DataKey::PendingAdmin, proposal);
+    env.storage().persistent().extend_ttl(
+        &DataKey::PendingAdmin,
+        LEDGER_TTL_THRESHOLD,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
+/// Clear the admin handover proposal
+pub fn remove_pending_admin(env: &Env) {
+    env.storage().persistent().remove(&DataKey::PendingAdmin);
+}
+
+/// Get the admin council, if one is set
+pub fn get_admin_council(env: &Env) -> Option<AdminCouncil> {
+    env.storage().persistent().get(&DataKey::AdminCouncil)
+}
+
+/// Set the admin council
+pub fn set_admin_council(env: &Env, council: &AdminCouncil) {
+    env.storage().persistent().set(&DataKey::AdminCouncil, council);
+    env.storage().persistent().extend_ttl(
+        &DataKey::AdminCouncil,
+        LEDGER_TTL_THRESHOLD,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
+/// Remove the admin council, going back to a single admin
+pub fn remove_admin_council(env: &Env) {
+    env.storage().persistent().remove(&DataKey::AdminCouncil);
+}
+
+/// Get the council signers who approved an admin action
+pub fn get_admin_approvals(env: &Env, action_hash: &BytesN<32>) -> Vec<Address> {
+    let key = DataKey::AdminApprovals(action_hash.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Store the council signers who approved an admin action
+pub fn set_admin_approvals(env: &Env, action_hash: &BytesN<32>, approvals: &Vec<Address>) {
+    let key = DataKey::AdminApprovals(action_hash.clone());
+    env.storage().persistent().set(&key, approvals);
+}
+
+/// Clear an admin action's approvals once it has been carried out
+pub fn remove_admin_approvals(env: &Env, action_hash: &BytesN<32>) {
+    let key = DataKey::AdminApprovals(action_hash.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Check whether a part of the contract is paused
+pub fn is_paused(env: &Env, scope: PauseScope) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Paused(scope))
+        .unwrap_or(false)
+}
+
+/// Pause or unpause a part of the contract
+pub fn set_paused(env: &Env, scope: PauseScope, paused: bool) {
+    let key = DataKey::Paused(scope);
+    if paused {
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
 // ============================================
 // Token Storage Functions
 // ============================================
@@ -169,10 +267,127 @@ pub fn set_token(env: &Env, token: &Address) {
     );
 }
 
+/// Get the tokens the admin has allowed besides the default one
+pub fn get_allowed_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AllowedTokens)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Store the tokens the admin has allowed besides the default one
+pub fn set_allowed_tokens(env: &Env, tokens: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AllowedTokens, tokens);
+    env.storage().persistent().extend_ttl(
+        &DataKey::AllowedTokens,
+        LEDGER_TTL_THRESHOLD,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
+/// Check whether new splits may be created in a token
+///
+/// The default token set at initialization is always allowed.
+pub fn is_token_allowed(env: &Env, token: &Address) -> bool {
+    *token == get_token(env) || get_allowed_tokens(env).contains(token)
+}
+
+// ============================================
+// Schema Version Functions
+// ============================================
+
+/// Get the layout version of the stored records
+///
+/// Contracts deployed before versioning have no key, which reads as 0.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+/// Set the layout version of the stored records
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SchemaVersion, &version);
+    env.storage().persistent().extend_ttl(
+        &DataKey::SchemaVersion,
+        LEDGER_TTL_THRESHOLD,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
+/// Get the next split ID the running migration will look at
+pub fn get_migration_cursor(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or(1)
+}
+
+/// Save how far the running migration has got
+pub fn set_migration_cursor(env: &Env, cursor: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MigrationCursor, &cursor);
+    env.storage().persistent().extend_ttl(
+        &DataKey::MigrationCursor,
+        LEDGER_TTL_THRESHOLD,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
+/// Clear the cursor once a migration finishes
+pub fn remove_migration_cursor(env: &Env) {
+    env.storage().persistent().remove(&DataKey::MigrationCursor);
+}
+
+/// Rewrite a split stored in an older layout, if it is one
+///
+/// I'm reading the record as a plain map first: decoding a struct with the
+/// wrong field set traps rather than returning an error, so the layout is
+/// told apart by the fields each schema added (`deadline` in 1, `token` in
+/// 2). Old splits were all paid in the contract's default token. Returns
+/// whether anything changed.
+pub fn migrate_split(env: &Env, split_id: u64) -> bool {
+    let key = DataKey::Split(split_id);
+    let raw: Option<Map<Symbol, Val>> = env.storage().persistent().get(&key);
+    let Some(raw) = raw else {
+        return false;
+    };
+    if raw.contains_key(Symbol::new(env, "token")) {
+        return false;
+    }
+
+    let token = get_token(env);
+    let split = if raw.contains_key(Symbol::new(env, "deadline")) {
+        SplitV1::try_from_val(env, &raw.to_val())
+            .expect("Unknown split layout")
+            .upgrade(token)
+    } else {
+        SplitV0::try_from_val(env, &raw.to_val())
+            .expect("Unknown split layout")
+            .upgrade(env, token)
+    };
+    set_split(env, split_id, &split);
+    true
+}
+
 // ============================================
 // Original Split Counter Functions
 // ============================================
 
+/// Get the highest split ID handed out so far
+pub fn get_split_count(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SplitCounter)
+        .unwrap_or(0)
+}
+
 /// Get the next split ID and increment the counter
 ///
 /// I'm using a simple incrementing counter for split IDs.
@@ -216,6 +431,21 @@ pub fn set_split(env: &Env, split_id: u64, split: &Split) {
         .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
 }
 
+/// Get the recipients a split pays out to, if they were set at creation
+pub fn get_split_recipients(env: &Env, split_id: u64) -> Option<Vec<Recipient>> {
+    let key = DataKey::SplitRecipients(split_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Store the recipients a split pays out to
+pub fn set_split_recipients(env: &Env, split_id: u64, recipients: &Vec<Recipient>) {
+    let key = DataKey::SplitRecipients(split_id);
+    env.storage().persistent().set(&key, recipients);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
 /// Remove a split (for cleanup if needed)
 #[allow(dead_code)]
 pub fn remove_split(env: &Env, split_id: u64) {
@@ -254,12 +484,9 @@ pub fn increment_escrow_count(env: &Env) -> u64 {
 }
 
 /// Get an escrow by split_id
-pub fn get_escrow(env: &Env, split_id: &String) -> SplitEscrow {
+pub fn get_escrow(env: &Env, split_id: &String) -> Option<SplitEscrow> {
     let key = StorageKey::Escrow(split_id.clone());
-    env.storage()
-        .persistent()
-        .get(&key)
-        .expect("Escrow not found")
+    env.storage().persistent().get(&key)
 }
 
 /// Check if an escrow exists
@@ -341,13 +568,29 @@ pub fn remove_participant_payment(env: &Env, split_id: &String, participant: &Ad
 
 /// Generate a unique escrow ID string
 ///
-/// I'm combining a counter with a prefix for readable IDs.
-pub fn generate_escrow_id(env: &Env) -> String {
-    let _count = increment_escrow_count(env);
-    // Create a simple string ID like "escrow-1", "escrow-2", etc.
-    // For now, just use the count as the ID since String::from_str
-    // with formatting isn't available in no_std
-    String::from_str(env, "escrow")
+/// I'm combining the escrow counter with a short sha256 of the creator,
+/// counter and ledger sequence, giving IDs like `esc-42-9f86d081`. The
+/// counter alone guarantees uniqueness; the hash suffix keeps IDs from
+/// being trivially guessable, the same idea as `generate_dispute_id`.
+pub fn generate_escrow_id(env: &Env, creator: &Address) -> String {
+    let count = increment_escrow_count(env);
+
+    let mut input = creator.clone().to_xdr(env);
+    input.append(&Bytes::from_array(env, &count.to_be_bytes()));
+    input.append(&Bytes::from_array(env, &env.ledger().sequence().to_be_bytes()));
+    let hash = env.crypto().sha256(&input).to_array();
+
+    // "esc-" + up to 20 digits + "-" + 8 hex chars
+    let mut buf = [0u8; 4 + format::MAX_U64_DECIMAL_LEN + 1 + 8];
+    let mut len = 0;
+    buf[..4].copy_from_slice(b"esc-");
+    len += 4;
+    len += format::write_u64(&mut buf[len..], count);
+    buf[len] = b'-';
+    len += 1;
+    len += format::write_bytes_hex(&mut buf[len..], &hash[..4]);
+
+    String::from_bytes(env, &buf[..len])
 }
 
 // ============================================
@@ -378,6 +621,16 @@ pub fn get_next_claim_id(env: &Env) -> u64 {
     next
 }
 
+/// Generate the next insurance ID, e.g. `ins-7`
+pub fn generate_insurance_id(env: &Env) -> String {
+    format::prefixed_id(env, b"ins-", get_next_insurance_id(env))
+}
+
+/// Generate the next claim ID, e.g. `clm-3`
+pub fn generate_claim_id(env: &Env) -> String {
+    format::prefixed_id(env, b"clm-", get_next_claim_id(env))
+}
+
 /// Store an insurance policy
 pub fn set_insurance(env: &Env, insurance_id: &String, policy: &crate::types::InsurancePolicy) {
     let key = StorageKey::Insurance(insurance_id.clone());
@@ -476,6 +729,67 @@ pub fn add_insurance_claim(env: &Env, insurance_id: &String, claim_id: &String)
         .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
 }
 
+/// Get all claim IDs filed against an insurance policy
+pub fn get_insurance_claims(env: &Env, insurance_id: &String) -> Vec<String> {
+    let key = StorageKey::InsuranceClaims(insurance_id.clone());
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+}
+
+/// Key for a token's insurance pool
+///
+/// The default token's pool keeps the key it had before pools were per
+/// token, so existing deployments don't lose it.
+fn insurance_pool_key(env: &Env, token: &Address) -> StorageKey {
+    if *token == get_token(env) {
+        StorageKey::InsurancePool
+    } else {
+        StorageKey::TokenInsurancePool(token.clone())
+    }
+}
+
+/// Get a token's insurance pool, or an empty one if nothing has been recorded
+pub fn get_insurance_pool(env: &Env, token: &Address) -> crate::types::InsurancePool {
+    env.storage()
+        .persistent()
+        .get(&insurance_pool_key(env, token))
+        .unwrap_or_default()
+}
+
+/// Store a token's insurance pool
+pub fn set_insurance_pool(env: &Env, token: &Address, pool: &crate::types::InsurancePool) {
+    let key = insurance_pool_key(env, token);
+    env.storage().persistent().set(&key, pool);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Key for a liquidity provider's capital in one token, kept like the pool's
+fn pool_provider_key(env: &Env, provider: &Address, token: &Address) -> StorageKey {
+    if *token == get_token(env) {
+        StorageKey::PoolProvider(provider.clone())
+    } else {
+        StorageKey::TokenPoolProvider(provider.clone(), token.clone())
+    }
+}
+
+/// Record a liquidity provider's capital contribution to a token's pool
+pub fn add_pool_provider_capital(env: &Env, provider: &Address, token: &Address, amount: i128) -> i128 {
+    let key = pool_provider_key(env, provider, token);
+    let total: i128 = env.storage().persistent().get(&key).unwrap_or(0) + amount;
+    env.storage().persistent().set(&key, &total);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+    total
+}
+
+/// Get the capital a liquidity provider has contributed to a token's pool
+pub fn get_pool_provider_capital(env: &Env, provider: &Address, token: &Address) -> i128 {
+    let key = pool_provider_key(env, provider, token);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
 /// Storage keys for rewards system
 #[derive(Clone)]
 #[contracttype]
@@ -483,6 +797,13 @@ pub enum RewardsStorageKey {
     UserRewards(Address),
     UserActivity(Address, u64),
     ActivityCounter,
+    RewardsToken,
+    RewardsTreasury,
+    RewardsLiabilities,
+    RewardsConfig,
+    RewardsCheckpoint(Address),
+    EpochEmissions(u64),
+    VolumeRate(Address),
 }
 
 /// Get user rewards data
@@ -523,6 +844,96 @@ pub fn get_next_activity_id(env: &Env) -> u64 {
     id
 }
 
+/// Get the token rewards are paid in
+///
+/// Falls back to the escrow token until the admin configures one.
+pub fn get_rewards_token(env: &Env) -> Address {
+    let key = RewardsStorageKey::RewardsToken;
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| get_token(env))
+}
+
+/// Set the token rewards are paid in
+pub fn set_rewards_token(env: &Env, token: &Address) {
+    let key = RewardsStorageKey::RewardsToken;
+    env.storage().persistent().set(&key, token);
+}
+
+/// Get the rewards treasury balance
+pub fn get_rewards_treasury(env: &Env) -> i128 {
+    let key = RewardsStorageKey::RewardsTreasury;
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the rewards treasury balance
+pub fn set_rewards_treasury(env: &Env, balance: i128) {
+    let key = RewardsStorageKey::RewardsTreasury;
+    env.storage().persistent().set(&key, &balance);
+}
+
+/// Get the rewards earned by all users but not yet claimed
+pub fn get_rewards_liabilities(env: &Env) -> i128 {
+    let key = RewardsStorageKey::RewardsLiabilities;
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Adjust the unclaimed rewards total by `delta`, never going below zero
+pub fn adjust_rewards_liabilities(env: &Env, delta: i128) {
+    let key = RewardsStorageKey::RewardsLiabilities;
+    let total = core::cmp::max(get_rewards_liabilities(env) + delta, 0);
+    env.storage().persistent().set(&key, &total);
+}
+
+/// Get the reward formula, falling back to the original defaults
+pub fn get_rewards_config(env: &Env) -> RewardsConfig {
+    let key = RewardsStorageKey::RewardsConfig;
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+/// Set the reward formula
+pub fn set_rewards_config(env: &Env, config: &RewardsConfig) {
+    let key = RewardsStorageKey::RewardsConfig;
+    env.storage().persistent().set(&key, config);
+}
+
+/// Get the rate a token's volume counts towards rewards at, if one is set
+pub fn get_volume_rate(env: &Env, token: &Address) -> Option<VolumeRate> {
+    let key = RewardsStorageKey::VolumeRate(token.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Set the rate a token's volume counts towards rewards at
+pub fn set_volume_rate(env: &Env, token: &Address, rate: &VolumeRate) {
+    let key = RewardsStorageKey::VolumeRate(token.clone());
+    env.storage().persistent().set(&key, rate);
+}
+
+/// Get the activity a user has already been rewarded for
+pub fn get_rewards_checkpoint(env: &Env, user: &Address) -> RewardsCheckpoint {
+    let key = RewardsStorageKey::RewardsCheckpoint(user.clone());
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+/// Set the activity a user has already been rewarded for
+pub fn set_rewards_checkpoint(env: &Env, user: &Address, checkpoint: &RewardsCheckpoint) {
+    let key = RewardsStorageKey::RewardsCheckpoint(user.clone());
+    env.storage().persistent().set(&key, checkpoint);
+}
+
+/// Get the rewards credited so far in an epoch
+pub fn get_epoch_emissions(env: &Env, epoch: u64) -> i128 {
+    let key = RewardsStorageKey::EpochEmissions(epoch);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the rewards credited so far in an epoch
+pub fn set_epoch_emissions(env: &Env, epoch: u64, emitted: i128) {
+    let key = RewardsStorageKey::EpochEmissions(epoch);
+    env.storage().persistent().set(&key, &emitted);
+}
+
 /// Storage keys for oracle system
 #[derive(Clone)]
 #[contracttype]
@@ -530,12 +941,31 @@ pub enum OracleStorageKey {
     VerificationRequest(String),
     OracleConfig,
     VerificationCounter,
+    Attestation(String, Address),
+    VerificationPolicy,
+    SplitRequiresVerification(u64),
+    SplitVerifications(String),
+    LatestVerification(String),
 }
 
 /// Get verification request
+///
+/// Requests stored before quorum voting have no `approvals` field. I'm
+/// reading the record as a plain map first, as `migrate_split` does, and
+/// upgrading old ones with no votes counted; they're saved in the current
+/// layout the next time they change.
 pub fn get_verification_request(env: &Env, verification_id: &String) -> Option<VerificationRequest> {
     let key = OracleStorageKey::VerificationRequest(verification_id.clone());
-    env.storage().persistent().get(&key)
+    let raw: Map<Symbol, Val> = env.storage().persistent().get(&key)?;
+    if raw.contains_key(Symbol::new(env, "approvals")) {
+        return Some(VerificationRequest::try_from_val(env, &raw.to_val()).expect("Unknown verification layout"));
+    }
+
+    Some(
+        VerificationRequestV0::try_from_val(env, &raw.to_val())
+            .expect("Unknown verification layout")
+            .upgrade(),
+    )
 }
 
 /// Set verification request
@@ -562,48 +992,171 @@ pub fn set_oracle_config(env: &Env, config: &OracleConfig) {
     env.storage().persistent().set(&key, config);
 }
 
+/// Get the global verification policy (disabled by default)
+pub fn get_verification_policy(env: &Env) -> VerificationPolicy {
+    let key = OracleStorageKey::VerificationPolicy;
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+/// Set the global verification policy
+pub fn set_verification_policy(env: &Env, policy: &VerificationPolicy) {
+    let key = OracleStorageKey::VerificationPolicy;
+    env.storage().persistent().set(&key, policy);
+}
+
+/// Check whether a split's creator opted it into verification
+pub fn split_requires_verification(env: &Env, split_id: u64) -> bool {
+    let key = OracleStorageKey::SplitRequiresVerification(split_id);
+    env.storage().persistent().has(&key)
+}
+
+/// Opt a split into verification
+pub fn set_split_requires_verification(env: &Env, split_id: u64) {
+    let key = OracleStorageKey::SplitRequiresVerification(split_id);
+    env.storage().persistent().set(&key, &true);
+}
+
+/// Get an oracle's vote on a verification request, if it has voted
+pub fn get_attestation(env: &Env, verification_id: &String, oracle: &Address) -> Option<bool> {
+    let key = OracleStorageKey::Attestation(verification_id.clone(), oracle.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Record an oracle's vote on a verification request
+pub fn set_attestation(env: &Env, verification_id: &String, oracle: &Address, verified: bool) {
+    let key = OracleStorageKey::Attestation(verification_id.clone(), oracle.clone());
+    env.storage().persistent().set(&key, &verified);
+}
+
+/// Get the number of verification requests created so far
+pub fn get_verification_count(env: &Env) -> u64 {
+    let key = OracleStorageKey::VerificationCounter;
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
 /// Get next verification ID
 pub fn get_next_verification_id(env: &Env) -> String {
     let key = OracleStorageKey::VerificationCounter;
-    let counter = env.storage().persistent().get(&key).unwrap_or(0u64);
+    let counter = get_verification_count(env);
     env.storage().persistent().set(&key, &(counter + 1));
-    
-    // Convert counter to string
-    format_number_as_string(&env, counter)
-}
-
-/// Helper to format number as string (reused from rewards)
-fn format_number_as_string(env: &Env, num: u64) -> String {
-    match num {
-        0 => String::from_str(env, "0"),
-        1 => String::from_str(env, "1"),
-        2 => String::from_str(env, "2"),
-        3 => String::from_str(env, "3"),
-        4 => String::from_str(env, "4"),
-        5 => String::from_str(env, "5"),
-        6 => String::from_str(env, "6"),
-        7 => String::from_str(env, "7"),
-        8 => String::from_str(env, "8"),
-        9 => String::from_str(env, "9"),
-        10 => String::from_str(env, "10"),
-        _ => String::from_str(env, "999"),
-    }
+
+    format::u64_to_string(env, counter)
 }
 
-/// Get all verification requests for a split
+/// Get all verification requests for a split, oldest first
 pub fn get_split_verifications(env: &Env, split_id: &String) -> Vec<String> {
-    let mut verification_ids = Vec::new(env);
-    let counter = get_next_verification_id(env);
-    
-    // Search through all verification IDs
-    for i in 0..counter {
-        let id_str = format_number_as_string(env, i);
-        if let Some(request) = get_verification_request(env, &id_str) {
-            if request.split_id == *split_id {
-                verification_ids.push_back(id_str);
-            }
-        }
+    let key = OracleStorageKey::SplitVerifications(split_id.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Add a verification request to its split's index
+pub fn add_split_verification(env: &Env, split_id: &String, verification_id: &String) {
+    let key = OracleStorageKey::SplitVerifications(split_id.clone());
+    let mut verification_ids = get_split_verifications(env, split_id);
+    verification_ids.push_back(verification_id.clone());
+    env.storage().persistent().set(&key, &verification_ids);
+}
+
+/// Get the most recently decided verification request for a split
+pub fn get_latest_verification(env: &Env, split_id: &String) -> Option<String> {
+    let key = OracleStorageKey::LatestVerification(split_id.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Point a split at its most recently decided verification request
+pub fn set_latest_verification(env: &Env, split_id: &String, verification_id: &String) {
+    let key = OracleStorageKey::LatestVerification(split_id.clone());
+    env.storage().persistent().set(&key, verification_id);
+}
+
+/// Storage keys for platform fees
+#[derive(Clone)]
+#[contracttype]
+pub enum FeeStorageKey {
+    FeeConfig,
+    FeeBalance(Address),
+    FeeExempt(Address),
+    SplitFees(u64),
+    FeeBounds(Address),
+}
+
+/// Get the platform fee config, if fees are switched on
+pub fn get_fee_config(env: &Env) -> Option<FeeConfig> {
+    env.storage().persistent().get(&FeeStorageKey::FeeConfig)
+}
+
+/// Set the platform fee config
+pub fn set_fee_config(env: &Env, config: &FeeConfig) {
+    let key = FeeStorageKey::FeeConfig;
+    env.storage().persistent().set(&key, config);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Get the fee bounds set for a token, if any
+pub fn get_fee_bounds(env: &Env, token: &Address) -> Option<FeeBounds> {
+    let key = FeeStorageKey::FeeBounds(token.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Set the fee bounds for a token
+pub fn set_fee_bounds(env: &Env, token: &Address, bounds: &FeeBounds) {
+    let key = FeeStorageKey::FeeBounds(token.clone());
+    env.storage().persistent().set(&key, bounds);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Get the fees collected in a token and not yet withdrawn
+pub fn get_fee_balance(env: &Env, token: &Address) -> i128 {
+    let key = FeeStorageKey::FeeBalance(token.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the fees collected in a token and not yet withdrawn
+pub fn set_fee_balance(env: &Env, token: &Address, balance: i128) {
+    let key = FeeStorageKey::FeeBalance(token.clone());
+    env.storage().persistent().set(&key, &balance);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Check whether an address is exempt from platform fees
+pub fn is_fee_exempt(env: &Env, address: &Address) -> bool {
+    let key = FeeStorageKey::FeeExempt(address.clone());
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+/// Exempt an address from platform fees, or end its exemption
+pub fn set_fee_exempt(env: &Env, address: &Address, exempt: bool) {
+    let key = FeeStorageKey::FeeExempt(address.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+    } else {
+        env.storage().persistent().remove(&key);
     }
-    
-    verification_ids
+}
+
+/// Get the fees already taken from a split's releases
+pub fn get_split_fees(env: &Env, split_id: u64) -> i128 {
+    let key = FeeStorageKey::SplitFees(split_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the fees already taken from a split's releases
+pub fn set_split_fees(env: &Env, split_id: u64, fees: i128) {
+    let key = FeeStorageKey::SplitFees(split_id);
+    env.storage().persistent().set(&key, &fees);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
 }
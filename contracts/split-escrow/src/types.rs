@@ -6,8 +6,8 @@
 //! This module includes both the original types and the enhanced escrow
 //! types as specified in issue #59.
 
-use soroban_sdk::{contracterror, contracttype, Address, Env, String, Vec};
-use soroban_sdk::{Address, Vec};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
 // ============================================
 // Original Types (preserved for compatibility)
 // ============================================
@@ -18,8 +18,9 @@ use soroban_sdk::{Address, Vec};
 /// - Pending: Created but no deposits yet
 /// - Active: At least one deposit received
 /// - Completed: All participants have paid their share
-/// - Released: Funds have been released to the creator
+/// - Released: Funds have been released to the recipients
 /// - Cancelled: Split was cancelled, refunds may be needed
+/// - Expired: Deadline passed before the split was funded, refunds may be needed
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SplitStatus {
@@ -28,6 +29,7 @@ pub enum SplitStatus {
     Completed,
     Released,
     Cancelled,
+    Expired,
 }
 
 /// A participant in a split
@@ -35,7 +37,7 @@ pub enum SplitStatus {
 /// I'm tracking both the owed amount and paid amount separately
 /// to support partial payments and payment verification.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Participant {
     /// The participant's Stellar address
     pub address: Address,
@@ -48,6 +50,23 @@ pub struct Participant {
 
     /// Whether the participant has fully paid their share
     pub has_paid: bool,
+
+    /// The amount returned to this participant after a cancellation
+    pub amount_refunded: i128,
+}
+
+/// Someone paid out when a split's funds are released
+///
+/// Each release is shared between a split's recipients in proportion to
+/// their weights.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Recipient {
+    /// The recipient's Stellar address
+    pub address: Address,
+
+    /// The recipient's relative share of each release
+    pub weight: u32,
 }
 
 /// A bill split record
@@ -55,7 +74,7 @@ pub struct Participant {
 /// I'm storing all split data in a single struct for atomic operations.
 /// The participants vector allows any number of people to share a bill.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Split {
     /// Unique identifier for this split
     pub id: u64,
@@ -72,7 +91,7 @@ pub struct Split {
     /// Amount collected so far from participants
     pub amount_collected: i128,
 
-    /// Amount already released to the creator
+    /// Amount already released to the recipients
     pub amount_released: i128,
 
     /// List of participants and their share details
@@ -83,10 +102,14 @@ pub struct Split {
 
     /// Timestamp when the split was created
     pub created_at: u64,
+
+    /// Optional timestamp after which the split can be expired
+    pub deadline: Option<u64>,
+
+    /// The token participants pay in and the creator is paid out in
+    pub token: Address,
 }
 
-/// Contract errors
-#[contracterror]
 /// Rewards status for user rewards
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -98,7 +121,7 @@ pub enum RewardsStatus {
 
 /// User rewards data structure
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UserRewards {
     pub user: Address,
     pub total_splits_created: u64,
@@ -110,6 +133,141 @@ pub struct UserRewards {
     pub status: RewardsStatus,
 }
 
+/// Reward formula settings
+///
+/// I'm keeping every knob here so the admin can tune the formula without an
+/// upgrade. A zero cap, epoch or decay period switches that limit off.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardsConfig {
+    /// Reward per split created
+    pub creation_reward: i128,
+
+    /// Reward per split participated in
+    pub participation_reward: i128,
+
+    /// Reward on transacted volume, in basis points
+    pub volume_bps: u32,
+
+    /// Lifetime cap on rewards earned per user (0 = uncapped)
+    pub max_rewards_per_user: i128,
+
+    /// Length of an emission epoch in seconds (0 = no epochs)
+    pub epoch_duration: u64,
+
+    /// Rewards credited across all users per epoch (0 = uncapped)
+    pub epoch_emission_limit: i128,
+
+    /// Inactivity after which new rewards start decaying, in seconds (0 = no decay)
+    pub decay_period: u64,
+
+    /// Share of new rewards lost per full decay period, in basis points
+    pub decay_bps: u32,
+}
+
+impl RewardsConfig {
+    /// The original formula: 10 per split created, 5 per participation and
+    /// 0.1% of volume, with no caps or decay
+    pub fn new() -> Self {
+        Self {
+            creation_reward: 10,
+            participation_reward: 5,
+            volume_bps: 10,
+            max_rewards_per_user: 0,
+            epoch_duration: 0,
+            epoch_emission_limit: 0,
+            decay_period: 0,
+            decay_bps: 0,
+        }
+    }
+
+    /// Check the settings make sense before storing them
+    pub fn is_valid(&self) -> bool {
+        self.creation_reward >= 0
+            && self.participation_reward >= 0
+            && self.max_rewards_per_user >= 0
+            && self.epoch_emission_limit >= 0
+            && self.volume_bps <= 10_000
+            && self.decay_bps <= 10_000
+    }
+
+    /// Rewards for a batch of activity before caps and decay
+    pub fn activity_rewards(&self, splits_created: u64, splits_participated: u64, amount_transacted: i128) -> i128 {
+        splits_created as i128 * self.creation_reward
+            + splits_participated as i128 * self.participation_reward
+            + amount_transacted * self.volume_bps as i128 / 10_000
+    }
+
+    /// Reduce `amount` for every full decay period the user has been inactive
+    pub fn apply_decay(&self, amount: i128, inactive_for: u64) -> i128 {
+        if self.decay_period == 0 {
+            return amount;
+        }
+
+        let periods = (inactive_for / self.decay_period) as i128;
+        let decay_bps = core::cmp::min(periods * self.decay_bps as i128, 10_000);
+        amount * (10_000 - decay_bps) / 10_000
+    }
+}
+
+impl Default for RewardsConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Activity totals a user has already been rewarded for
+///
+/// `calculate_rewards` only prices activity past this point, which is what
+/// grandfathers rewards earned under an earlier `RewardsConfig`.
+#[contracttype]
+#[derive(Clone, Debug, Default)]
+pub struct RewardsCheckpoint {
+    pub splits_created: u64,
+    pub splits_participated: u64,
+    pub amount_transacted: i128,
+}
+
+/// Conversion of a token's volume into default-token units for rewards
+///
+/// A volume of `amount` in the token counts as
+/// `amount * numerator / denominator` of the default token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VolumeRate {
+    pub numerator: i128,
+    pub denominator: i128,
+}
+
+impl VolumeRate {
+    /// Check the rate can't be negative or divide by zero
+    pub fn is_valid(&self) -> bool {
+        self.numerator >= 0 && self.denominator > 0
+    }
+
+    /// Convert a volume in the token into default-token units
+    pub fn convert(&self, amount: i128) -> i128 {
+        amount.saturating_mul(self.numerator) / self.denominator
+    }
+}
+
+/// Rewards treasury snapshot
+///
+/// I'm reporting the balance next to what's been earned but not yet
+/// claimed, so it's easy to see whether every user could claim right now.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RewardsTreasury {
+    /// Token rewards are paid in
+    pub token: Address,
+
+    /// Tokens the admin has funded and that haven't been claimed yet
+    pub balance: i128,
+
+    /// Rewards earned by users but not yet claimed
+    pub unclaimed_liabilities: i128,
+}
+
 /// User activity tracking for rewards calculation
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -155,11 +313,16 @@ pub struct VerificationRequest {
     pub verified_by: Option<Address>,
     pub verified_at: Option<u64>,
     pub rejection_reason: Option<String>,
+    pub approvals: u32,
+    pub rejections: u32,
 }
 
 /// Oracle configuration and settings
+///
+/// A request is decided once `required_verifications` oracles agree, and
+/// expires if that hasn't happened within `verification_timeout` seconds.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct OracleConfig {
     pub required_verifications: u32,
     pub verification_timeout: u64,
@@ -167,31 +330,54 @@ pub struct OracleConfig {
     pub oracle_addresses: Vec<Address>,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-#[repr(u32)]
-pub enum Error {
-    SplitNotFound = 1,
-    SplitCancelled = 2,
-    SplitReleased = 3,
-    SplitNotFunded = 4,
-    SplitFullyFunded = 5,
-    NoFundsAvailable = 6,
-    InvalidAmount = 7,
-    InsuranceNotFound = 8,
-    InsuranceAlreadyExists = 9,
-    ClaimNotFound = 10,
-    InvalidClaimStatus = 11,
-    InsufficientPremium = 12,
-    InsuranceExpired = 13,
-    ParticipantNotFound = 14,
-    UserNotFound = 15,
-    InsufficientRewards = 16,
-    RewardsAlreadyClaimed = 17,
-    VerificationNotFound = 18,
-    VerificationAlreadyExists = 19,
-    InvalidVerificationStatus = 20,
-    OracleNotAuthorized = 21,
-    InsufficientOracles = 22,
+/// When splits must be verified before their funds are released
+///
+/// With the policy enabled, any split whose total is above
+/// `amount_threshold` is held until the oracles verify it. Creators can also
+/// opt individual splits in regardless of size.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VerificationPolicy {
+    pub enabled: bool,
+    pub amount_threshold: i128,
+}
+
+/// Longest an admin can give the oracles to decide a request (30 days)
+pub const MAX_VERIFICATION_TIMEOUT: u64 = 30 * 24 * 60 * 60;
+
+impl OracleConfig {
+    /// Single-oracle config used when the first oracle is added
+    pub fn new(env: &Env) -> Self {
+        Self {
+            required_verifications: 1,
+            verification_timeout: 48 * 60 * 60,
+            min_oracles: 1,
+            oracle_addresses: Vec::new(env),
+        }
+    }
+
+    /// Check the quorum can actually be reached by the configured oracles
+    /// within a sensible timeout
+    pub fn is_valid(&self) -> bool {
+        let count = self.oracle_addresses.len();
+        if self.required_verifications == 0 || self.required_verifications > count {
+            return false;
+        }
+
+        if self.verification_timeout == 0 || self.verification_timeout > MAX_VERIFICATION_TIMEOUT {
+            return false;
+        }
+
+        // Each oracle only gets one vote, so duplicates would inflate the set
+        for i in 0..count {
+            let oracle = self.oracle_addresses.get(i).unwrap();
+            if self.oracle_addresses.last_index_of(&oracle) != Some(i) {
+                return false;
+            }
+        }
+
+        true
+    }
 }
 
 /// Configuration for the contract
@@ -199,7 +385,7 @@ pub enum Error {
 /// I'm keeping this minimal for now but it can be extended
 /// to include fee settings, limits, etc.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContractConfig {
     /// The contract administrator address
     pub admin: Address,
@@ -208,6 +394,143 @@ pub struct ContractConfig {
     pub is_paused: bool,
 }
 
+/// An admin handover waiting for the new admin to accept
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposal {
+    /// The address that will become admin once it accepts
+    pub proposed_admin: Address,
+
+    /// When the proposal was made
+    pub proposed_at: u64,
+
+    /// After this timestamp the proposal can no longer be accepted
+    pub expires_at: Option<u64>,
+}
+
+/// Signers who must approve the admin's configuration changes
+///
+/// With a council set, each config change needs `threshold` of `signers`
+/// to approve that exact call before the admin can make it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminCouncil {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+impl AdminCouncil {
+    /// Check the threshold is reachable and no signer is listed twice
+    pub fn is_valid(&self) -> bool {
+        if self.threshold == 0 || self.threshold > self.signers.len() {
+            return false;
+        }
+
+        for i in 0..self.signers.len() {
+            let signer = self.signers.get(i).unwrap();
+            if self.signers.last_index_of(&signer) != Some(i) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Which part of the contract a pause applies to
+///
+/// `All` stops every state-changing call except the ways out: cancelling,
+/// expiring and refunds. The others let the admin stop one area on its own.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseScope {
+    /// Everything below, plus verification
+    All,
+    /// New splits and escrows, and payments into them
+    Deposits,
+    /// Paying collected funds out to creators
+    Releases,
+    /// Buying policies, claims and pool deposits
+    Insurance,
+    /// Accruing and claiming rewards
+    Rewards,
+}
+
+/// Platform fee taken from split releases
+///
+/// The fee is `fee_bps` of the amount released, raised to `min_fee` and
+/// limited to `max_fee` when one is set. It is never more than the release
+/// itself. Fees build up in the contract until withdrawn to `treasury`.
+/// `min_fee` and `max_fee` are in the default token; other tokens use the
+/// `FeeBounds` set for them, or none.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    pub fee_bps: u32,
+    pub min_fee: i128,
+    pub max_fee: Option<i128>,
+    pub treasury: Address,
+}
+
+impl FeeConfig {
+    /// Check the rate is at most 100% and the bounds make sense
+    pub fn is_valid(&self) -> bool {
+        self.fee_bps <= 10_000 && self.bounds().is_valid()
+    }
+
+    /// The fee bounds for the default token
+    pub fn bounds(&self) -> FeeBounds {
+        FeeBounds {
+            min_fee: self.min_fee,
+            max_fee: self.max_fee,
+        }
+    }
+
+    /// Work out the fee on an amount, within a token's bounds
+    pub fn fee_for(&self, amount: i128, bounds: &FeeBounds) -> i128 {
+        if amount <= 0 {
+            return 0;
+        }
+
+        let mut fee = core::cmp::max(amount * self.fee_bps as i128 / 10_000, bounds.min_fee);
+        if let Some(max_fee) = bounds.max_fee {
+            fee = core::cmp::min(fee, max_fee);
+        }
+        core::cmp::min(fee, amount)
+    }
+}
+
+/// Minimum and maximum platform fee for one token
+///
+/// Amounts are in that token's own units, so each token needs its own.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeBounds {
+    pub min_fee: i128,
+    pub max_fee: Option<i128>,
+}
+
+impl FeeBounds {
+    /// No minimum and no cap
+    pub fn none() -> Self {
+        Self {
+            min_fee: 0,
+            max_fee: None,
+        }
+    }
+
+    /// Check the minimum isn't negative or above the cap
+    pub fn is_valid(&self) -> bool {
+        if self.min_fee < 0 {
+            return false;
+        }
+        match self.max_fee {
+            Some(max_fee) => max_fee >= self.min_fee,
+            None => true,
+        }
+    }
+}
+
 // ============================================
 // Insurance Types
 // ============================================
@@ -269,6 +592,62 @@ pub struct InsurancePolicy {
     pub expires_at: u64,
 }
 
+/// Insurance pool accounting
+///
+/// I'm tracking reserves against the coverage currently outstanding so the
+/// contract can tell whether it could actually pay every active policy.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct InsurancePool {
+    /// Tokens held to pay claims (premiums + capital - payouts)
+    pub reserves: i128,
+
+    /// Sum of `coverage_amount` over all active policies
+    pub total_exposure: i128,
+
+    /// Lifetime premiums collected
+    pub total_premiums: i128,
+
+    /// Lifetime capital deposited by the admin or liquidity providers
+    pub total_capital: i128,
+
+    /// Lifetime claim payouts
+    pub total_payouts: i128,
+
+    /// Minimum reserves / exposure, in basis points, for new policies
+    pub min_reserve_ratio_bps: u32,
+}
+
+impl InsurancePool {
+    /// Create an empty pool with no reserve requirement
+    pub fn new() -> Self {
+        Self {
+            reserves: 0,
+            total_exposure: 0,
+            total_premiums: 0,
+            total_capital: 0,
+            total_payouts: 0,
+            min_reserve_ratio_bps: 0,
+        }
+    }
+
+    /// Check the pool would still meet its minimum ratio with extra exposure
+    ///
+    /// `reserves / exposure >= min_ratio` is checked as a cross
+    /// multiplication so there's no rounding.
+    pub fn can_cover(&self, extra_reserves: i128, extra_exposure: i128) -> bool {
+        let reserves = self.reserves + extra_reserves;
+        let exposure = self.total_exposure + extra_exposure;
+        reserves * 10_000 >= exposure * self.min_reserve_ratio_bps as i128
+    }
+}
+
+impl Default for InsurancePool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Insurance claim for split protection
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -314,7 +693,7 @@ pub struct InsuranceClaim {
 pub enum EscrowStatus {
     /// Escrow is active and accepting payments
     Active,
-    /// All payments received, ready for release
+    /// All payments received and released to the creator
     Completed,
     /// Escrow was cancelled by creator
     Cancelled,
@@ -327,7 +706,7 @@ pub enum EscrowStatus {
 /// I'm adding `paid_at` to track exactly when each participant
 /// completed their payment, which is useful for auditing and disputes.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EscrowParticipant {
     /// The participant's Stellar address
     pub address: Address,
@@ -347,7 +726,7 @@ pub struct EscrowParticipant {
 /// I designed this to support the full escrow lifecycle with
 /// deadline enforcement and detailed participant tracking.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SplitEscrow {
     /// Unique split identifier (string format for flexibility)
     pub split_id: String,
@@ -522,3 +901,136 @@ pub fn create_escrow(
     }
 }
 
+// ============================================
+// Schema Migration Types
+// ============================================
+
+/// A participant as stored before refunds were tracked (schema 0)
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ParticipantV0 {
+    pub address: Address,
+    pub share_amount: i128,
+    pub amount_paid: i128,
+    pub has_paid: bool,
+}
+
+/// A split as stored before deadlines and refunds existed (schema 0)
+///
+/// `migrate` reads old records through this and rewrites them as `Split`.
+/// `SplitEscrow` hasn't changed shape since schema 0, so it has no
+/// counterpart here.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SplitV0 {
+    pub id: u64,
+    pub creator: Address,
+    pub description: String,
+    pub total_amount: i128,
+    pub amount_collected: i128,
+    pub amount_released: i128,
+    pub participants: Vec<ParticipantV0>,
+    pub status: SplitStatus,
+    pub created_at: u64,
+}
+
+impl SplitV0 {
+    /// Convert to the current layout, with nothing refunded and no deadline
+    pub fn upgrade(self, env: &Env, token: Address) -> Split {
+        let mut participants = Vec::new(env);
+        for p in self.participants.iter() {
+            participants.push_back(Participant {
+                address: p.address,
+                share_amount: p.share_amount,
+                amount_paid: p.amount_paid,
+                has_paid: p.has_paid,
+                amount_refunded: 0,
+            });
+        }
+
+        Split {
+            id: self.id,
+            creator: self.creator,
+            description: self.description,
+            total_amount: self.total_amount,
+            amount_collected: self.amount_collected,
+            amount_released: self.amount_released,
+            participants,
+            status: self.status,
+            created_at: self.created_at,
+            deadline: None,
+            token,
+        }
+    }
+}
+
+/// A split as stored before each split had its own token (schema 1)
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SplitV1 {
+    pub id: u64,
+    pub creator: Address,
+    pub description: String,
+    pub total_amount: i128,
+    pub amount_collected: i128,
+    pub amount_released: i128,
+    pub participants: Vec<Participant>,
+    pub status: SplitStatus,
+    pub created_at: u64,
+    pub deadline: Option<u64>,
+}
+
+impl SplitV1 {
+    /// Convert to the current layout, paying in the given token
+    pub fn upgrade(self, token: Address) -> Split {
+        Split {
+            id: self.id,
+            creator: self.creator,
+            description: self.description,
+            total_amount: self.total_amount,
+            amount_collected: self.amount_collected,
+            amount_released: self.amount_released,
+            participants: self.participants,
+            status: self.status,
+            created_at: self.created_at,
+            deadline: self.deadline,
+            token,
+        }
+    }
+}
+
+/// A verification request as stored before quorum voting added vote counts
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VerificationRequestV0 {
+    pub verification_id: String,
+    pub split_id: String,
+    pub requester: Address,
+    pub receipt_hash: String,
+    pub evidence_url: Option<String>,
+    pub submitted_at: u64,
+    pub status: VerificationStatus,
+    pub verified_by: Option<Address>,
+    pub verified_at: Option<u64>,
+    pub rejection_reason: Option<String>,
+}
+
+impl VerificationRequestV0 {
+    /// Convert to the current layout with no votes counted yet
+    pub fn upgrade(self) -> VerificationRequest {
+        VerificationRequest {
+            verification_id: self.verification_id,
+            split_id: self.split_id,
+            requester: self.requester,
+            receipt_hash: self.receipt_hash,
+            evidence_url: self.evidence_url,
+            submitted_at: self.submitted_at,
+            status: self.status,
+            verified_by: self.verified_by,
+            verified_at: self.verified_at,
+            rejection_reason: self.rejection_reason,
+            approvals: 0,
+            rejections: 0,
+        }
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Defines contract events emitted by the template management functions.
 
-use soroban_sdk::{Address, Env, String, Symbol};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol};
 
 /// Emit an event when a template is created.
 pub fn emit_template_created(env: &Env, template_id: String, creator: Address, name: String) {
@@ -19,3 +19,17 @@ pub fn emit_template_used(env: &Env, template_id: String, split_id: String) {
         split_id,
     );
 }
+
+/// Emit an event when the admin replaces the contract code.
+pub fn emit_upgraded(env: &Env, new_wasm_hash: BytesN<32>) {
+    env.events()
+        .publish((Symbol::new(env, "upgraded"),), new_wasm_hash);
+}
+
+/// Emit an event when stored templates move to a new schema version.
+pub fn emit_migrated(env: &Env, from_version: u32, to_version: u32) {
+    env.events().publish(
+        (Symbol::new(env, "migrated"),),
+        (from_version, to_version),
+    );
+}
@@ -6,7 +6,7 @@
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
 mod events;
 mod storage;
@@ -21,12 +21,93 @@ pub use storage::*;
 pub use types::*;
 pub use utils::*;
 
+/// Layout version of the templates this build writes.
+///
+/// Bump this when `Template` or `Participant` gains or loses a field, and
+/// rewrite the old records in `migrate`.
+const SCHEMA_VERSION: u32 = 1;
+
 /// The Split Template contract for managing reusable split configurations.
 #[contract]
 pub struct SplitTemplateContract;
 
 #[contractimpl]
 impl SplitTemplateContract {
+    /// Set the admin who may upgrade the contract.
+    ///
+    /// Templates themselves stay permissionless; the admin only manages
+    /// upgrades and migrations.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The admin address (must authorize)
+    ///
+    /// # Returns
+    /// Success, or an error if an admin is already set
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if storage::get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        storage::set_admin(&env, &admin);
+        storage::set_schema_version(&env, SCHEMA_VERSION);
+
+        Ok(())
+    }
+
+    /// Replace the contract code with an uploaded WASM.
+    ///
+    /// Stored templates are kept as is; call `migrate` afterwards if the new
+    /// code changes their layout.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `new_wasm_hash` - Hash of the WASM already uploaded to the network
+    ///
+    /// # Returns
+    /// Success, or an error if no admin is set
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        let admin = storage::get_admin(&env).ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        events::emit_upgraded(&env, new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Get the layout version of the stored templates.
+    pub fn get_schema_version(env: Env) -> u32 {
+        storage::get_schema_version(&env)
+    }
+
+    /// Bring stored templates up to the current schema version.
+    ///
+    /// `Template` hasn't changed shape since the first release, so for now
+    /// this only records the version on deployments that predate it.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    /// The schema version now stored, or an error if no admin is set
+    pub fn migrate(env: Env) -> Result<u32, Error> {
+        let admin = storage::get_admin(&env).ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let from_version = storage::get_schema_version(&env);
+        if from_version < SCHEMA_VERSION {
+            storage::set_schema_version(&env, SCHEMA_VERSION);
+            events::emit_migrated(&env, from_version, SCHEMA_VERSION);
+        }
+
+        Ok(SCHEMA_VERSION)
+    }
+
     /// Create a new split template with the given configuration.
     ///
     /// Generates a deterministic template ID based on creator, name, and current ledger time.
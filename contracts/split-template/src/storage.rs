@@ -20,6 +20,16 @@ pub struct CreatorKey {
     pub creator: Address,
 }
 
+/// Contract-wide settings
+#[contracttype]
+#[derive(Clone)]
+pub enum ConfigKey {
+    /// The address allowed to upgrade the contract
+    Admin,
+    /// Layout version of the stored templates
+    SchemaVersion,
+}
+
 // Time-to-live for persistent storage (about 1 year)
 const LEDGER_TTL_PERSISTENT: u32 = 31_536_000;
 
@@ -71,3 +81,40 @@ pub fn get_creator_template_ids(env: &Env, creator: &Address) -> Vec<String> {
         .get(&key)
         .unwrap_or_else(|| Vec::new(env))
 }
+
+/// Retrieve the contract admin, if one has been set.
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&ConfigKey::Admin)
+}
+
+/// Store the contract admin.
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().persistent().set(&ConfigKey::Admin, admin);
+    env.storage().persistent().extend_ttl(
+        &ConfigKey::Admin,
+        LEDGER_TTL_PERSISTENT,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
+/// Retrieve the layout version of the stored templates.
+///
+/// Deployments from before versioning have no key, which reads as 0.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+/// Store the layout version of the stored templates.
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::SchemaVersion, &version);
+    env.storage().persistent().extend_ttl(
+        &ConfigKey::SchemaVersion,
+        LEDGER_TTL_PERSISTENT,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
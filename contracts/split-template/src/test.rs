@@ -2,10 +2,13 @@
 
 #[cfg(test)]
 mod tests {
-    use soroban_sdk::{testutils::Address as _, Address, Env, String as SorobanString, Vec as SorobanVec};
+    use soroban_sdk::{
+        testutils::{Address as _, MockAuth, MockAuthInvoke},
+        Address, Bytes, Env, IntoVal, String as SorobanString, Vec as SorobanVec,
+    };
 
     use crate::{SplitTemplateContract, SplitTemplateContractClient};
-    use crate::types::{Participant, SplitType};
+    use crate::types::{Error, Participant, SplitType};
 
     fn setup() -> (Env, Address, SplitTemplateContractClient<'static>) {
         let env = Env::default();
@@ -448,6 +451,98 @@ mod tests {
         // and the require_auth call is made
     }
 
+    // ============================================
+    // Upgrade Tests
+    // ============================================
+
+    /// Smallest WASM the host accepts: just the environment meta section
+    const MINIMAL_WASM: [u8; 40] = [
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic and version
+        0x00, 0x1e, 0x11, // custom section, 30 bytes, 17-byte name
+        b'c', b'o', b'n', b't', b'r', b'a', b'c', b't', b'e', b'n', b'v', b'm', b'e', b't',
+        b'a', b'v', b'0', // "contractenvmetav0"
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, // interface v21
+    ];
+
+    #[test]
+    fn test_initialize_only_once() {
+        let (env, _creator, client) = setup();
+        let admin = Address::generate(&env);
+
+        assert_eq!(client.get_schema_version(), 0);
+        client.initialize(&admin);
+        assert_eq!(client.get_schema_version(), 1);
+
+        let result = client.try_initialize(&Address::generate(&env));
+        assert_eq!(result, Err(Ok(Error::AlreadyInitialized)));
+    }
+
+    #[test]
+    fn test_upgrade_keeps_templates() {
+        let (env, creator, client) = setup();
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        let name = SorobanString::from_str(&env, "Before Upgrade");
+        let participants = create_percentage_split_participants(&env, &[60, 40]);
+        let template_id =
+            client.create_template(&creator, &name, &SplitType::Percentage, &participants);
+
+        let wasm_hash = env
+            .deployer()
+            .upload_contract_wasm(Bytes::from_slice(&env, &MINIMAL_WASM));
+        client.upgrade(&wasm_hash);
+
+        // Stand the native contract back up as "v2" at the same address
+        env.register_contract(Some(&client.address), SplitTemplateContract);
+        assert_eq!(client.migrate(), 1);
+
+        let template = client.get_template(&template_id);
+        assert_eq!(template.name, name);
+        assert_eq!(template.participants.len(), 2);
+        assert_eq!(client.get_templates(&creator).len(), 1);
+    }
+
+    #[test]
+    fn test_migrate_records_version_on_old_deployments() {
+        let (env, _creator, client) = setup();
+
+        // No admin yet, so nobody can migrate or upgrade
+        assert_eq!(client.try_migrate(), Err(Ok(Error::NotInitialized)));
+
+        // An old deployment picks up an admin without a version, then migrates
+        client.initialize(&Address::generate(&env));
+        env.as_contract(&client.address, || {
+            crate::storage::set_schema_version(&env, 0);
+        });
+        assert_eq!(client.get_schema_version(), 0);
+
+        assert_eq!(client.migrate(), 1);
+        assert_eq!(client.get_schema_version(), 1);
+    }
+
+    #[test]
+    fn test_upgrade_requires_admin() {
+        let (env, _creator, client) = setup();
+        client.initialize(&Address::generate(&env));
+
+        let wasm_hash = env
+            .deployer()
+            .upload_contract_wasm(Bytes::from_slice(&env, &MINIMAL_WASM));
+
+        let attacker = Address::generate(&env);
+        env.mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "upgrade",
+                args: (wasm_hash.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_upgrade(&wasm_hash).is_err());
+    }
+
     // ============================================
     // Edge Cases
     // ============================================
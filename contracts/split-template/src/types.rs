@@ -53,4 +53,8 @@ pub enum Error {
     InvalidParticipants = 2,
     /// Shares are invalid for the given split type
     InvalidShares = 3,
+    /// The contract already has an admin
+    AlreadyInitialized = 4,
+    /// No admin has been set yet
+    NotInitialized = 5,
 }